@@ -0,0 +1,22 @@
+// Writes a with_years!() macro that passes every src/days/day_factory/year_xxxx.rs module name to the
+// macro it is given, so a new year is registered just by adding its file
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let factory = Path::new("src").join("days").join("day_factory");
+    println!("cargo:rerun-if-changed={}", factory.display());
+
+    let mut years: Vec<String> = fs::read_dir(&factory)
+        .expect("src/days/day_factory can be read")
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter_map(|name| name.strip_suffix(".rs").map(str::to_string))
+        .filter(|name| name.strip_prefix("year_").is_some_and(|y| !y.is_empty() && y.chars().all(|c| c.is_ascii_digit())))
+        .collect();
+    years.sort();
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("years.rs");
+    fs::write(out, format!("macro_rules! with_years {{\n    ($m:ident) => {{ $m!({}); }};\n}}\n", years.join(", "))).unwrap();
+}
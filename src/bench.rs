@@ -0,0 +1,192 @@
+use std::error::Error;
+use std::fs::{read_to_string, write};
+use std::time::{Duration, Instant};
+
+use crate::days;
+use crate::input_reader;
+use crate::runner;
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / secs.len() as f64;
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "min": self.min.as_secs_f64(),
+            "median": self.median.as_secs_f64(),
+            "mean": self.mean.as_secs_f64(),
+            "stddev": self.stddev.as_secs_f64(),
+        })
+    }
+}
+
+pub struct StageBench {
+    pub year: String,
+    pub day: String,
+    pub input: String,
+    pub stage: String,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+impl StageBench {
+    // Identifies this measurement in a saved baseline
+    pub fn key(&self) -> String {
+        format!("{}-{}-{}-{}", self.year, self.day, self.input, self.stage)
+    }
+}
+
+pub struct BenchConfig {
+    pub base_dir: String,
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+// Runs a single stage repeatedly, splitting the time spent in the input reader (parsing) from the rest (solving).
+pub fn bench_stage(year: &str, day: &str, input: &str, stage: &str, config: &BenchConfig) -> Result<StageBench, Box<dyn Error>> {
+    let info = days::find_day(year, day)?;
+    let (year, day) = (info.year_name(), info.day_name());
+    let mut parse = Vec::new();
+    let mut solve = Vec::new();
+    let mut total = Vec::new();
+
+    for i in 0..config.warmup + config.iterations {
+        let ipr = input_reader::InputReader::new(
+            day.to_string() + "-" + input,
+            config.base_dir.to_string() + "/" + &year[..],
+            stage.to_string(),
+        );
+        let timer = ipr.parse_timer.clone();
+
+        let start = Instant::now();
+        if stage == "both" {
+            days::run_both(&year, &day, ipr)?;
+        } else {
+            days::run_day(&year, &day, ipr)?;
+        }
+        let elapsed = start.elapsed();
+
+        if i >= config.warmup {
+            let parse_time = timer.elapsed().min(elapsed);
+            parse.push(parse_time);
+            solve.push(elapsed - parse_time);
+            total.push(elapsed);
+        }
+    }
+
+    Ok(StageBench {
+        year,
+        day,
+        input: input.to_string(),
+        stage: stage.to_string(),
+        iterations: config.iterations,
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+        total: Stats::from_samples(&total),
+    })
+}
+
+pub fn save_baseline(path: &str, results: &[StageBench]) -> Result<(), Box<dyn Error>> {
+    let mut out = serde_json::Map::new();
+    for r in results {
+        out.insert(r.key(), serde_json::json!({
+            "iterations": r.iterations,
+            "parse": r.parse.to_json(),
+            "solve": r.solve.to_json(),
+            "total": r.total.to_json(),
+        }));
+    }
+    write(path, serde_json::to_string_pretty(&serde_json::Value::Object(out))?)?;
+    Ok(())
+}
+
+pub fn load_baseline(path: &str) -> Result<serde_json::Value, Box<dyn Error>> {
+    Ok(read_to_string(path)?.parse()?)
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.3}s", secs)
+    } else if secs >= 0.001 {
+        format!("{:.3}ms", secs * 1e3)
+    } else {
+        format!("{:.3}us", secs * 1e6)
+    }
+}
+
+fn baseline_median(baseline: &serde_json::Value, key: &str, phase: &str) -> Option<Duration> {
+    baseline[key][phase]["median"].as_f64().map(Duration::from_secs_f64)
+}
+
+pub fn print_report(results: &[StageBench], baseline: Option<&serde_json::Value>) {
+    let mut header = vec!["Day", "Stage", "Phase", "Min", "Median", "Mean", "Stddev"];
+    if baseline.is_some() {
+        header.push("Base median");
+        header.push("Delta");
+    }
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for r in results {
+        for (phase, stats) in [("parse", &r.parse), ("solve", &r.solve), ("total", &r.total)] {
+            let mut row = vec![
+                format!("{} {} {}", r.year, r.day, r.input),
+                r.stage.to_string(),
+                phase.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.stddev),
+            ];
+            if let Some(b) = baseline {
+                match baseline_median(b, &r.key(), phase) {
+                    Some(base) => {
+                        let delta = if base.is_zero() {
+                            0.0
+                        } else {
+                            (stats.median.as_secs_f64() / base.as_secs_f64() - 1.0) * 100.0
+                        };
+                        row.push(format_duration(base));
+                        row.push(format!("{:+.1}%", delta));
+                    }
+                    None => {
+                        row.push(String::from("-"));
+                        row.push(String::from("-"));
+                    }
+                }
+            }
+            rows.push(row);
+        }
+    }
+
+    runner::print_table(&header, &rows);
+}
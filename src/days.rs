@@ -1,10 +1,29 @@
 use std::error::Error;
 
+use crate::error::AocError;
 use crate::input_reader;
 
 mod day_factory;
 
-pub fn run_day(day: String, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
+pub use day_factory::{geometry3d, ocr, search, types, Day, DayInfo, Solution};
+
+pub fn get_day(year: &str, day: &str) -> Result<Box<dyn Day>, Box<dyn Error>> {
+    day_factory::get_day(year, day)
+}
+
+pub fn run_day(year: &str, day: &str, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
     
-    day_factory::get_day(day)?.run(ipr)
+    day_factory::get_day(year, day)?.run(ipr)
+}
+
+pub fn run_both(year: &str, day: &str, ipr: input_reader::InputReader) -> Result<(String, String), Box<dyn Error>> {
+    day_factory::get_day(year, day)?.run_both(ipr)
+}
+
+pub fn find_day(year: &str, day: &str) -> Result<&'static DayInfo, AocError> {
+    day_factory::find_day(year, day)
+}
+
+pub fn list_days() -> Vec<&'static DayInfo> {
+    day_factory::registry().collect()
 }
@@ -1,22 +1,12 @@
 use std::error::Error;
-use std::fmt;
 
+use crate::error::AocError;
 use crate::input_reader;
 
-mod types;
-mod year_2021;
-mod year_2022;
-
-#[derive(Debug)]
-struct DayError(String);
-
-impl fmt::Display for DayError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "There is an error: {}", self.0)
-    }
-}
-
-impl Error for DayError {}
+pub mod types;
+pub mod ocr;
+pub mod geometry3d;
+pub mod search;
 
 pub trait Day {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>>;
@@ -25,15 +15,108 @@ pub trait Day {
         match ipr.stage.as_str() {
             "1" => self.run1(ipr),
             "2" => self.run2(ipr),
-            _ => Err(Box::new(DayError("Unknown Stage".into()))),
+            s => Err(Box::new(AocError::UnknownStage(s.to_string()))),
         }
     }
+    fn run_both(&self, ipr: input_reader::InputReader) -> Result<(String, String), Box<dyn Error>> {
+        Ok((self.run1(ipr.clone())?, self.run2(ipr)?))
+    }
+}
+
+// A day that parses its input once into Input, which both parts then share. Every Solution is
+// also a Day, so days can be moved over to it one at a time and are registered the same way.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, ipr: &input_reader::InputReader) -> Result<Self::Input, Box<dyn Error>>;
+    fn part1(&self, input: &Self::Input) -> Result<String, Box<dyn Error>>;
+    fn part2(&self, input: &Self::Input) -> Result<String, Box<dyn Error>>;
+}
+
+impl<T: Solution> Day for T {
+    fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
+        self.part1(&self.parse(&ipr)?)
+    }
+
+    fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
+        self.part2(&self.parse(&ipr)?)
+    }
+
+    fn run_both(&self, ipr: input_reader::InputReader) -> Result<(String, String), Box<dyn Error>> {
+        let input = self.parse(&ipr)?;
+        Ok((self.part1(&input)?, self.part2(&input)?))
+    }
+}
+
+// Every day file declares its INFO and a year file lists its day modules with days!(). The years are
+// every year_xxxx.rs next to this file, found by build.rs, so nothing else needs editing to add a day or year.
+pub struct DayInfo {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub create: fn() -> Box<dyn Day>,
+}
+
+impl DayInfo {
+    pub fn year_name(&self) -> String {
+        self.year.to_string()
+    }
+
+    // Zero padded to match the input file names e.g. 01-A
+    pub fn day_name(&self) -> String {
+        format!("{:02}", self.day)
+    }
+}
+
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub const DAYS: &[$crate::days::day_factory::DayInfo] = &[$($day::INFO),*];
+    };
+}
+
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(mod $year;)*
+
+        const YEARS: &[&[DayInfo]] = &[$($year::DAYS),*];
+    };
 }
 
-pub fn get_day(day: String) -> Result<Box<dyn Day>, Box<dyn Error>> {
-    match &day[0..4] {
-        "2021" => year_2021::get_day(day[4..6].to_string()),
-        "2022" => year_2022::get_day(day[4..6].to_string()),
-        _ => Err(Box::new(DayError("Unknown Year".into()))),
+// build.rs finds the year_xxxx.rs files, the modules are declared here so their files are found next to this one
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
+with_years!(years);
+
+pub fn registry() -> impl Iterator<Item = &'static DayInfo> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+// Accepts the year and day with or without zero padding e.g. "2022" and "1" or "01"
+pub fn find_day(year: &str, day: &str) -> Result<&'static DayInfo, AocError> {
+    let unknown_year = || {
+        let mut available: Vec<String> = registry().map(|i| i.year_name()).collect();
+        available.dedup();
+        AocError::UnknownYear { year: year.to_string(), available }
+    };
+    let y: u32 = year.trim().parse().map_err(|_| unknown_year())?;
+
+    let in_year: Vec<&DayInfo> = registry().filter(|i| i.year == y).collect();
+    if in_year.is_empty() {
+        return Err(unknown_year());
     }
-}
\ No newline at end of file
+
+    let d: Option<u32> = day.trim().parse().ok();
+    match in_year.iter().find(|i| Some(i.day) == d) {
+        Some(info) => Ok(info),
+        None => Err(AocError::UnknownDay {
+            year: y.to_string(),
+            day: day.to_string(),
+            available: in_year.iter().map(|i| i.day_name()).collect(),
+        }),
+    }
+}
+
+pub fn get_day(year: &str, day: &str) -> Result<Box<dyn Day>, Box<dyn Error>> {
+    Ok((find_day(year, day)?.create)())
+}
@@ -0,0 +1,165 @@
+// Shapes made of unit cubes on the Point3D grid, e.g. the lava droplet in 2022 day 18. A cube at a point
+// shares a face with each of its six side neighbours, see Point3D::SIDE_DELTAS.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::types::Point3D;
+
+// An axis aligned box of cubes including both corners. Like Bounds it always holds at least one cube.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Box3D {
+    min: Point3D,
+    max: Point3D,
+}
+
+impl Box3D {
+    // None unless min is no further than max on every axis
+    pub fn new(min: Point3D, max: Point3D) -> Option<Self> {
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    pub fn min(&self) -> Point3D {
+        self.min
+    }
+
+    pub fn max(&self) -> Point3D {
+        self.max
+    }
+
+    // The smallest box holding every point, None if there are none
+    pub fn around(points: impl IntoIterator<Item = Point3D>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self { min: first, max: first }, |b, p| Self {
+            min: Point3D { x: b.min.x.min(p.x), y: b.min.y.min(p.y), z: b.min.z.min(p.z) },
+            max: Point3D { x: b.max.x.max(p.x), y: b.max.y.max(p.y), z: b.max.z.max(p.z) },
+        }))
+    }
+
+    pub fn contains(&self, p: &Point3D) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y) && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    // Grown by n on every side, or shrunk for a negative n. None if that would leave no cubes.
+    pub fn expand(&self, n: i64) -> Option<Self> {
+        Self::new(
+            Point3D { x: self.min.x - n, y: self.min.y - n, z: self.min.z - n },
+            Point3D { x: self.max.x + n, y: self.max.y + n, z: self.max.z + n },
+        )
+    }
+
+    // The number of cubes in the box
+    pub fn volume(&self) -> i64 {
+        (self.max.x - self.min.x + 1) * (self.max.y - self.min.y + 1) * (self.max.z - self.min.z + 1)
+    }
+
+    // The cubes in both boxes, None if they do not overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point3D { x: self.min.x.max(other.min.x), y: self.min.y.max(other.min.y), z: self.min.z.max(other.min.z) };
+        let max = Point3D { x: self.max.x.min(other.max.x), y: self.max.y.min(other.max.y), z: self.max.z.min(other.max.z) };
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    // The cubes of self that are not in other, as at most six boxes that do not overlap. The slabs either
+    // side of other in x are cut off first, then those in y from what is left, then those in z.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        let mut parts = Vec::new();
+        let mut rest = *self;
+        if rest.min.x < cut.min.x {
+            parts.push(Self { max: Point3D { x: cut.min.x - 1, ..rest.max }, ..rest });
+        }
+        if rest.max.x > cut.max.x {
+            parts.push(Self { min: Point3D { x: cut.max.x + 1, ..rest.min }, ..rest });
+        }
+        rest.min.x = cut.min.x;
+        rest.max.x = cut.max.x;
+        if rest.min.y < cut.min.y {
+            parts.push(Self { max: Point3D { y: cut.min.y - 1, ..rest.max }, ..rest });
+        }
+        if rest.max.y > cut.max.y {
+            parts.push(Self { min: Point3D { y: cut.max.y + 1, ..rest.min }, ..rest });
+        }
+        rest.min.y = cut.min.y;
+        rest.max.y = cut.max.y;
+        if rest.min.z < cut.min.z {
+            parts.push(Self { max: Point3D { z: cut.min.z - 1, ..rest.max }, ..rest });
+        }
+        if rest.max.z > cut.max.z {
+            parts.push(Self { min: Point3D { z: cut.max.z + 1, ..rest.min }, ..rest });
+        }
+        parts
+    }
+
+    // Every point in the box, x changing fastest
+    pub fn points(&self) -> impl Iterator<Item = Point3D> {
+        let (min, max) = (self.min, self.max);
+        (min.z..=max.z).flat_map(move |z| (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3D { x, y, z })))
+    }
+}
+
+// Every point that can be reached from start by steps to a side neighbour that can_enter allows, including
+// start itself. can_enter has to say no somewhere around the points, e.g. outside a box, or this never ends.
+pub fn flood_fill(start: Point3D, mut can_enter: impl FnMut(&Point3D) -> bool) -> HashSet<Point3D> {
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(p) = queue.pop_front() {
+        for n in p.get_neighbours() {
+            if !seen.contains(&n) && can_enter(&n) {
+                seen.insert(n);
+                queue.push_back(n);
+            }
+        }
+    }
+    seen
+}
+
+// The faces of the cubes that do not touch another cube
+pub fn surface_area(cubes: &HashSet<Point3D>) -> usize {
+    cubes.iter().flat_map(|c| c.get_neighbours()).filter(|n| !cubes.contains(n)).count()
+}
+
+// The faces that can be reached from outside, so not those facing a pocket of air trapped inside the cubes
+pub fn exterior_area(cubes: &HashSet<Point3D>) -> usize {
+    // A layer of air all the way round means the outside is one region that reaches every exterior face
+    let Some(space) = Box3D::around(cubes.iter().copied()).and_then(|b| b.expand(1)) else {
+        return 0;
+    };
+    let outside = flood_fill(space.min, |p| space.contains(p) && !cubes.contains(p));
+    cubes.iter().flat_map(|c| c.get_neighbours()).filter(|n| outside.contains(n)).count()
+}
+
+// Labels each cube with the group of cubes it is joined to face to face. Groups are numbered from 0 in
+// the order of their smallest point so the labels are the same every run.
+pub fn label_components(cubes: &HashSet<Point3D>) -> HashMap<Point3D, usize> {
+    let mut sorted: Vec<&Point3D> = cubes.iter().collect();
+    sorted.sort();
+
+    let mut labels = HashMap::new();
+    let mut next = 0;
+    for c in sorted {
+        if labels.contains_key(c) {
+            continue;
+        }
+        for p in flood_fill(*c, |p| cubes.contains(p)) {
+            labels.insert(p, next);
+        }
+        next += 1;
+    }
+    labels
+}
+
+// The groups of cubes joined face to face, in the order of label_components
+pub fn connected_components(cubes: &HashSet<Point3D>) -> Vec<HashSet<Point3D>> {
+    let mut components: Vec<HashSet<Point3D>> = Vec::new();
+    for (p, label) in label_components(cubes) {
+        if components.len() <= label {
+            components.resize_with(label + 1, HashSet::new);
+        }
+        components[label].insert(p);
+    }
+    components
+}
@@ -0,0 +1,107 @@
+// Reads the block capitals that some puzzles draw instead of giving a printable answer.
+// There are two fonts, 4 wide by 6 high letters 5 columns apart and 6 wide by 10 high letters 8 columns apart.
+// A few letters such as Y in the small font are a column wider and fill the gap after them.
+
+struct Font {
+    height: usize,
+    spacing: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font { height: 6, spacing: 5, glyphs: &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+]};
+
+const LARGE: Font = Font { height: 10, spacing: 8, glyphs: &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+]};
+
+fn font(height: usize) -> Option<&'static Font> {
+    [&SMALL, &LARGE].into_iter().find(|f| f.height == height)
+}
+
+// Decodes the letters in a grid of lit pixels, one Vec per row. The first letter must start in the first
+// column, blank rows above and below are ignored and the font is picked from the height of what is left.
+// Returns None if any letter is not recognised.
+pub fn read_letters(rows: &[Vec<bool>]) -> Option<String> {
+    let lit = |r: &&Vec<bool>| r.iter().any(|&b| b);
+    let top = rows.iter().position(|r| lit(&r))?;
+    let bottom = rows.iter().rposition(|r| lit(&r))?;
+    let rows = &rows[top..=bottom];
+
+    let font = font(rows.len())?;
+    let used = rows.iter().filter_map(|r| r.iter().rposition(|&b| b)).max()? + 1;
+    let pixel = |x: usize, y: usize| if rows[y].get(x) == Some(&true) { '#' } else { '.' };
+
+    let mut letters = String::new();
+    for start in (0..used).step_by(font.spacing) {
+        // Each glyph is compared over its own width
+        let matches = |g: &[&str]| g.iter().enumerate().all(|(y, row)| row.chars().enumerate().all(|(x, c)| pixel(start + x, y) == c));
+        let (c, _) = font.glyphs.iter().find(|(_, g)| matches(g))?;
+        letters.push(*c);
+    }
+    Some(letters)
+}
+
+// The letters the font that is height pixels high can read, 6 or 10
+pub fn letters(height: usize) -> Vec<char> {
+    font(height).map(|f| f.glyphs.iter().map(|(c, _)| *c).collect()).unwrap_or_default()
+}
+
+// Draws text in the font that is height pixels high as a puzzle would, None if a letter is not in it
+pub fn draw_letters(text: &str, height: usize) -> Option<Vec<Vec<bool>>> {
+    let font = font(height)?;
+    let mut rows = vec![Vec::new(); height];
+    for (i, c) in text.chars().enumerate() {
+        let (_, glyph) = font.glyphs.iter().find(|(g, _)| *g == c)?;
+        for (row, line) in rows.iter_mut().zip(glyph.iter()) {
+            row.resize(i * font.spacing, false);
+            row.extend(line.chars().map(|p| p == '#'));
+        }
+    }
+    Some(rows)
+}
+
+// Draws the grid with # and . so an unreadable screen can still be returned and read by eye
+pub fn render(rows: &[Vec<bool>]) -> String {
+    rows.iter()
+        .map(|r| r.iter().map(|&b| if b { '#' } else { '.' }).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// The letters if they can be read, otherwise the drawing itself
+pub fn read_or_render(rows: &[Vec<bool>]) -> String {
+    read_letters(rows).unwrap_or_else(|| render(rows))
+}
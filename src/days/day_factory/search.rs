@@ -0,0 +1,121 @@
+// Shortest paths over a graph given as a closure from a node to its neighbours, so the graph never has to
+// be built up front. A node is anything that can be hashed e.g. a Point, or a (Point, time) state when what
+// can be reached changes over time.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    // From the start to the goal, both included
+    pub nodes: Vec<N>,
+}
+
+// The nodes reached so far, each with the one it was reached from and the lowest cost found to it
+struct Reached<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Reached<N, C> {
+    fn new(start: N, cost: C) -> Self {
+        Self { index: HashMap::from([(start.clone(), 0)]), nodes: vec![(start, None, cost)] }
+    }
+
+    fn path(&self, goal: usize) -> Path<N, C> {
+        let mut nodes = Vec::new();
+        let mut at = Some(goal);
+        while let Some(i) = at {
+            nodes.push(self.nodes[i].0.clone());
+            at = self.nodes[i].1;
+        }
+        nodes.reverse();
+        Path { cost: self.nodes[goal].2, nodes }
+    }
+}
+
+// The fewest steps from start to a node where is_goal holds, None if there is no way there
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let (node, _, steps) = reached.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(reached.path(i));
+        }
+        for n in neighbours(&node) {
+            if let Entry::Vacant(e) = reached.index.entry(n) {
+                let n = e.key().clone();
+                e.insert(reached.nodes.len());
+                queue.push_back(reached.nodes.len());
+                reached.nodes.push((n, Some(i), steps + 1));
+            }
+        }
+    }
+    None
+}
+
+// The lowest total cost from start to a node where is_goal holds. Each neighbour comes with the cost of the
+// step to it, which must not be negative.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::zero(), is_goal)
+}
+
+// As dijkstra, but looking first where heuristic says the goal is nearer. The heuristic is a guess at the
+// cost left from a node to the nearest goal and it must never guess too high, e.g. the manhattan distance
+// when every step costs at least 1, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I, mut heuristic: impl FnMut(&N) -> C, mut is_goal: impl FnMut(&N) -> bool) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::zero(), 0))]);
+    let mut reached = Reached::new(start, C::zero());
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // A cheaper way here was found after this one was queued
+        if cost > reached.nodes[i].2 {
+            continue;
+        }
+        let node = reached.nodes[i].0.clone();
+        if is_goal(&node) {
+            return Some(reached.path(i));
+        }
+        for (n, step) in neighbours(&node) {
+            let next = cost + step;
+            let j = match reached.index.entry(n) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if next >= reached.nodes[j].2 {
+                        continue;
+                    }
+                    reached.nodes[j].1 = Some(i);
+                    reached.nodes[j].2 = next;
+                    j
+                }
+                Entry::Vacant(e) => {
+                    let n = e.key().clone();
+                    let j = *e.insert(reached.nodes.len());
+                    reached.nodes.push((n, Some(i), next));
+                    j
+                }
+            };
+            heap.push(Reverse((next + heuristic(&reached.nodes[j].0), next, j)));
+        }
+    }
+    None
+}
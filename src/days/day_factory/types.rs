@@ -1,10 +1,33 @@
-use std::cmp::Ordering;
 use std::collections::HashSet;
-
-#[cfg(windows)]
-pub const DOUBLE_NEW_LINE: & str = "\r\n\r\n";
-#[cfg(not(windows))]
-pub const DOUBLE_NEW_LINE: & str = "\n\n";
+use std::fmt;
+
+use crate::error::{AocError, Context};
+
+// Splits text into the groups of lines between blank lines, for inputs made of sections. A line of only
+// whitespace counts as blank, runs of blank lines are one break and there are no empty groups. Each group
+// keeps its indentation as some sections, e.g. the map in 2022 day 22, line up by it.
+pub fn split_groups(s: &str) -> Vec<&str> {
+    let mut groups = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in s.split('\n') {
+        let line_start = offset;
+        offset += line.len() + 1;
+        if line.trim().is_empty() {
+            if let Some(st) = start.take() {
+                groups.push(&s[st..end]);
+            }
+            continue;
+        }
+        start.get_or_insert(line_start);
+        end = line_start + line.trim_end_matches('\r').len();
+    }
+    if let Some(st) = start {
+        groups.push(&s[st..end]);
+    }
+    groups
+}
 
 pub struct StrU64{
     pub dir: String,
@@ -12,10 +35,13 @@ pub struct StrU64{
 }
 
 impl std::str::FromStr for StrU64 {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let a:Vec<&str> = s.split_whitespace().collect();
+        if a.len() != 2 {
+            return Err(AocError::parse(s, "expected a word and a number"));
+        }
         Ok( StrU64{
             dir: a[0].to_string(),
             val: a[1].trim().parse()?,    
@@ -42,7 +68,7 @@ impl Bits {
 }
 
 impl std::str::FromStr for Bits {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut b: Vec<char> = Vec::new();
@@ -65,13 +91,13 @@ pub struct BingoBoard {
 }
 
 impl BingoBoard {
-    pub fn new(s: &str) -> Result<Self, std::num::ParseIntError> {
+    pub fn new(s: &str) -> Result<Self, AocError> {
         let mut bv: Vec<Vec<u64>> = Vec::new();
         let mut mv: Vec<Vec<bool>> = Vec::new();
         for line in s.lines() {
             let mut lv: Vec<u64> = Vec::new();
             for n in line.split_whitespace() {
-                lv.push(n.parse()?);
+                lv.push(n.parse().context(line)?);
             }
             mv.push(vec![false; lv.len()]);
             bv.push(lv);
@@ -133,14 +159,14 @@ impl BingoBoard {
 }
 
 impl std::str::FromStr for BingoBoard {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         BingoBoard::new(s)
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
 pub struct Point {
     pub x: usize,
     pub y: usize,
@@ -157,130 +183,245 @@ impl Point {
         Point { x: self.x, y: self.y }
     }
 
-    pub fn cmp(&self, c:&Self) -> Ordering {
-        if self.x == c.x {
-            return self.y.cmp(&c.y);
-        }
-        self.x.cmp(&c.x)
+    // The point a step of d away, None if that is below zero or past usize::MAX
+    pub fn step(&self, d: Point2<i64>) -> Option<Self> {
+        Some(Point { x: self.x.checked_add_signed(d.x as isize)?, y: self.y.checked_add_signed(d.y as isize)? })
     }
 
-    #[allow(dead_code)]
-    pub fn get_all_neighbours(&self, x_max_value: usize, y_max_value: usize) -> Vec<Self> {
-        let x_range = match self.x {
-            x if x > 0 && x < x_max_value => self.x-1..self.x+2,
-            0 => 0..self.x+2,
-            x if x == x_max_value => self.x-1..self.x+1,
-            _ => 0..0
-        };
-
-        let y_range = match self.y {
-            y if y > 0 && y < y_max_value => self.y-1..self.y+2,
-            0 => 0..self.y+2,
-            y if y == y_max_value => self.y-1..self.y+1,
-            _ => 0..0
-        };
-
-        let mut n:Vec<Self> = Vec::new();
-        for x in x_range {
-            for y in y_range.clone() {
-                if x == self.x && y == self.y {
-                    continue;
-                }
-                n.push(Self{x,y});
-            }
-        }
-        n
+    // The points a step away in each direction that are inside the bounds. self does not have to be inside
+    // e.g. a way in from just outside an edge.
+    fn neighbours_in(&self, deltas: &[Point2<i64>], bounds: &Bounds) -> Vec<Self> {
+        deltas.iter().filter_map(|&d| self.step(d)).filter(|p| bounds.contains(*p)).collect()
+    }
 
+    // Up, right, down and left, as Point2::deltas4
+    pub fn get_adjacent_neighbours(&self, bounds: &Bounds) -> Vec<Self> {
+        self.neighbours_in(&Point2::deltas4(), bounds)
     }
-    #[allow(dead_code)]
-    pub fn get_adjacent_neighbours(&self, x_max_value: usize, y_max_value: usize) -> Vec<Self> {
-        let x_range = match self.x {
-            x if x > 0 && x < x_max_value => self.x-1..self.x+2,
-            0 => 0..self.x+2,
-            x if x == x_max_value => self.x-1..self.x+1,
-            _ => 0..0
-        };
-
-        let y_range = match self.y {
-            y if y > 0 && y < y_max_value => self.y-1..self.y+2,
-            0 => 0..self.y+2,
-            y if y == y_max_value => self.y-1..self.y+1,
-            _ => 0..0
-        };
-
-        let mut n:Vec<Self> = Vec::new();
-        for x in x_range {
-            if x == self.x {
-                continue;
-            }
-            n.push(Self{x, y: self.y});
-        }
-        for y in y_range {
-            if y == self.y {
-                continue;
-            }
-            n.push(Self{x:self.x, y});
-        }
-        n
 
+    // Including the diagonals, as Point2::deltas8
+    pub fn get_all_neighbours(&self, bounds: &Bounds) -> Vec<Self> {
+        self.neighbours_in(&Point2::deltas8(), bounds)
     }
+}
 
-    pub fn get_adjacent_neighbours_min(&self, x_max_value: usize, y_max_value: usize, x_min_value: usize, y_min_value: usize) -> Vec<Self> {
-        let x_range = match self.x {
-            x if x > x_min_value && x < x_max_value => self.x-1..self.x+2,
-            x if x == x_min_value => self.x..self.x+2,
-            x if x+1 == x_min_value => self.x+1..self.x+2,
-            x if x == x_max_value => self.x-1..self.x+1,
-            x if x-1 == x_max_value => self.x-1..self.x,
-            _ => 0..0
-        };
-
-        let y_range = match self.y {
-            y if y > y_min_value && y < y_max_value => self.y-1..self.y+2,
-            y if y == y_min_value => self.y..self.y+2,
-            y if y+1 == y_min_value => self.y+1..self.y+2,
-            y if y == y_max_value => self.y-1..self.y+1,
-            y if y-1 == y_max_value => self.y-1..self.y,
-            _ => 0..0
-        };
-
-        let mut n:Vec<Self> = Vec::new();
-        if self.y >= y_min_value && self.y <= y_max_value{
-            for x in x_range {
-                if x == self.x {
-                    continue;
-                }
-                n.push(Self{x, y: self.y});
-            }
+impl std::str::FromStr for Point {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let vals:Vec<&str> = s.split(',').collect();
+        if vals.len() != 2 {
+            return Err(AocError::parse(s, "expected a point x,y"));
         }
+        Ok(Point{
+            x: vals[0].trim().parse()?,
+            y: vals[1].trim().parse()?,
+        })
+    }
+}
 
-        if self.x >= x_min_value && self.x <= x_max_value {
-            for y in y_range {
-                if y == self.y {
-                    continue;
-                }
-                n.push(Self{x:self.x, y});
-            }
+// A rectangle of points including both corners, min is the top left and max the bottom right. It always
+// holds at least one point.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Bounds {
+    min: Point,
+    max: Point,
+}
+
+impl Bounds {
+    // None unless min is above and left of max, or the same point
+    pub fn new(min: Point, max: Point) -> Option<Self> {
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    // The smallest bounds holding every point, None if there are none
+    pub fn around(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self { min: first, max: first }, |b, p| Self {
+            min: Point { x: b.min.x.min(p.x), y: b.min.y.min(p.y) },
+            max: Point { x: b.max.x.max(p.x), y: b.max.y.max(p.y) },
+        }))
+    }
+
+    pub fn width(&self) -> usize {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    // The nearest point inside
+    pub fn clamp(&self, p: Point) -> Point {
+        Point { x: p.x.clamp(self.min.x, self.max.x), y: p.y.clamp(self.min.y, self.max.y) }
+    }
+
+    // Grown by n on every side, though never below zero or past usize::MAX
+    pub fn expand(&self, n: usize) -> Self {
+        Self {
+            min: Point { x: self.min.x.saturating_sub(n), y: self.min.y.saturating_sub(n) },
+            max: Point { x: self.max.x.saturating_add(n), y: self.max.y.saturating_add(n) },
         }
+    }
 
-        n
+    // Every point a row at a time
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point { x, y }))
+    }
+}
+
+// A point, or a step between points, on a plane. x goes right and y goes down as in the puzzle
+// drawings, so up is y - 1. The steps and turns need a signed T, the distances work for any integer.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+fn abs_diff<T: num::Integer>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T: num::Integer + Copy> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    // The steps between the two moving only up, down, left and right
+    pub fn manhattan(&self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
 
+    // The steps between the two when diagonal moves are allowed too
+    pub fn chebyshev(&self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
     }
 }
 
-impl std::str::FromStr for Point {
-    type Err = std::num::ParseIntError;
+impl<T: num::Integer + num::Signed + Copy> Point2<T> {
+    pub fn up() -> Self {
+        Self::new(T::zero(), -T::one())
+    }
+
+    pub fn down() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+
+    pub fn left() -> Self {
+        Self::new(-T::one(), T::zero())
+    }
+
+    pub fn right() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    // The steps to the 4 points sharing an edge, clockwise from up
+    pub fn deltas4() -> [Self; 4] {
+        [Self::up(), Self::right(), Self::down(), Self::left()]
+    }
+
+    // The steps to all 8 points around, clockwise from up
+    pub fn deltas8() -> [Self; 8] {
+        let [u, r, d, l] = Self::deltas4();
+        [u, u + r, r, d + r, d, d + l, l, u + l]
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Self::deltas4().into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Self::deltas8().into_iter().map(move |d| self + d)
+    }
+
+    // A quarter turn clockwise as drawn, so right becomes down
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    // A quarter turn anticlockwise as drawn, so right becomes up
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    // A step of at most one in each direction towards where self points e.g. to walk a line
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T: num::Integer> std::ops::Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl<T: num::Integer> std::ops::Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+// Scales a step e.g. Point2::right() * 3
+impl<T: num::Integer + Copy> std::ops::Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+impl<T: num::Integer + num::Signed> std::ops::Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { x: -self.x, y: -self.y }
+    }
+}
+
+impl<T: num::Integer + Copy> std::ops::AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: num::Integer + Copy> std::ops::SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: std::str::FromStr> std::str::FromStr for Point2<T> where <T as std::str::FromStr>::Err: Into<AocError> {
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let vals:Vec<&str> = s.split(',').collect();
-        Ok(Point{
-            x: vals[0].trim().parse()?,
-            y: vals[1].trim().parse()?,
+        let (x, y) = s.split_once(',').ok_or_else(|| AocError::parse(s, "expected a point x,y"))?;
+        Ok(Self {
+            x: x.trim().parse().map_err(|e: T::Err| e.into().with_text(s))?,
+            y: y.trim().parse().map_err(|e: T::Err| e.into().with_text(s))?,
         })
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
 pub struct Point3D {
     pub x: i64,
     pub y: i64,
@@ -298,45 +439,16 @@ impl Point3D {
         }
         n
     }
-
-    pub fn max() -> Self {
-        Self{x: i64::MAX, y: i64::MAX, z: i64::MAX}
-    }
-
-    pub fn min() -> Self {
-        Self{x: i64::MIN, y: i64::MIN, z: i64::MIN}
-    }
-
-    pub fn update_min_max(&self, min: & mut Self, max: &mut Self) {
-        if self.x < min.x {
-            min.x = self.x;
-        }
-        if self.x > max.x {
-            max.x = self.x;
-        }
-
-        if self.y < min.y {
-            min.y = self.y;
-        }
-        if self.y > max.y {
-            max.y = self.y;
-        }
-
-        if self.z < min.z {
-            min.z = self.z;
-        }
-        if self.z > max.z {
-            max.z = self.z;
-        }
-
-    }
 }
 
 impl std::str::FromStr for Point3D {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let vals:Vec<&str> = s.split(',').collect();
+        if vals.len() != 3 {
+            return Err(AocError::parse(s, "expected a point x,y,z"));
+        }
         Ok(Self{
             x: vals[0].trim().parse()?,
             y: vals[1].trim().parse()?,
@@ -356,17 +468,8 @@ impl LineVector {
     }
 
     pub fn steps(&self) -> usize {
-        let x_steps = if self.s.x > self.e.x {
-            self.s.x - self.e.x
-        } else {
-            self.e.x - self.s.x
-        };
-
-        let y_steps = if self.s.y > self.e.y {
-            self.s.y - self.e.y
-        } else {
-            self.e.y - self.s.y
-        };
+        let x_steps = self.s.x.abs_diff(self.e.x);
+        let y_steps = self.s.y.abs_diff(self.e.y);
 
         if x_steps > y_steps {
             return x_steps+1;
@@ -376,10 +479,13 @@ impl LineVector {
 }
 
 impl std::str::FromStr for LineVector {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let points:Vec<&str> = s.split("->").collect();
+        if points.len() != 2 {
+            return Err(AocError::parse(s, "expected a line x1,y1 -> x2,y2"));
+        }
         Ok(LineVector {
             s: points[0].parse()?,
             e: points[1].parse()?,
@@ -414,57 +520,223 @@ impl Lines {
     }
 }
 
-pub struct CharNumGrid {
-    pub cells: Vec<Vec<u32>>,
+// A rectangle of cells stored a row at a time, x goes across and y goes down. The checked lookups take
+// signed coordinates so a step off any edge is None rather than an underflow.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
 }
 
-impl std::str::FromStr for CharNumGrid {
-    type Err = std::num::ParseIntError;
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Point) -> T) -> Self {
+        Self {
+            width,
+            height,
+            cells: (0..height).flat_map(|y| (0..width).map(move |x| Point { x, y })).map(f).collect(),
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(CharNumGrid {
-            cells: {
-                let mut v: Vec<Vec<u32>> = Vec::new();
-                for l in s.lines() {
-                    v.push(Vec::new());
-                    let last = v.len()-1;
-                    for c in l.chars() {
-                        v[last].push(c.to_digit(10).unwrap());
-                    }
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, |r| r.len());
+        if let Some((y, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(AocError::parse(&format!("row {}", y), format!("expected {} cells but found {}", width, r.len())));
+        }
+        Ok(Self { width, height: rows.len(), cells: rows.into_iter().flatten().collect() })
+    }
+
+    // One cell per character with each line a row, every line must be the same length
+    pub fn parse(s: &str, mut cell: impl FnMut(char) -> Result<T, AocError>) -> Result<Self, AocError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for l in s.lines() {
+            let row = l.chars().map(&mut cell).collect::<Result<Vec<T>, AocError>>().map_err(|e| e.with_text(l))?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(AocError::parse(l, format!("expected {} cells but found {}", first.len(), row.len())));
                 }
-                v
-            },
-        })
+            }
+            rows.push(row);
+        }
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y as usize * self.width + x as usize])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+        Some(&mut self.cells[y as usize * self.width + x as usize])
+    }
+
+    // As if the grid repeated forever in every direction
+    pub fn get_wrapped(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    // The point a step of d away from p, if it is on the grid
+    pub fn step(&self, p: Point, d: Point2<i64>) -> Option<Point> {
+        p.step(d).filter(|q| q.x < self.width && q.y < self.height)
+    }
+
+    // The neighbours sharing an edge, in the order of Point2::deltas4
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Point2::deltas4().into_iter().filter_map(move |d| self.step(p, d))
+    }
+
+    // All 8 neighbours, in the order of Point2::deltas8
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Point2::deltas8().into_iter().filter_map(move |d| self.step(p, d))
+    }
+
+    // The points from p, not including it, stepping in one direction until the edge. This walks a row,
+    // a column or a diagonal e.g. Point2::new(1, 1) goes down and to the right.
+    pub fn ray(&self, p: Point, d: Point2<i64>) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(self.step(p, d), move |&q| self.step(q, d))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the {}x{} grid", x, self.width, self.height);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    // Every cell with its point, a row at a time
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // The first point, a row at a time, whose cell matches
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, v)| f(v)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    // Swaps the rows and the columns, so the cell at x, y moves to y, x
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |p| self[Point { x: p.y, y: p.x }].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |p| self[Point { x: p.y, y: self.height - 1 - p.x }].clone())
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |p| self[Point { x: self.width - 1 - p.y, y: p.x }].clone())
+    }
+
+    // Mirrors left to right
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |p| self[Point { x: self.width - 1 - p.x, y: p.y }].clone())
+    }
+
+    // Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |p| self[Point { x: p.x, y: self.height - 1 - p.y }].clone())
+    }
+}
+
+impl<T> std::ops::Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(p.x < self.width && p.y < self.height, "{},{} is outside the {}x{} grid", p.x, p.y, self.width, self.height);
+        &self.cells[p.y * self.width + p.x]
     }
 }
 
+impl<T> std::ops::IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(p.x < self.width && p.y < self.height, "{},{} is outside the {}x{} grid", p.x, p.y, self.width, self.height);
+        &mut self.cells[p.y * self.width + p.x]
+    }
+}
+
+// Each cell with nothing between them, a line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, Ok)
+    }
+}
+
+// A cell mapper for Grid::parse and InputReader::grid
+pub fn digit(c: char) -> Result<u32, AocError> {
+    c.to_digit(10).ok_or_else(|| AocError::parse("", format!("'{}' is not a digit", c)))
+}
+
 pub struct CharList {
     pub items: Vec<char>,
 }
 
 impl std::str::FromStr for CharList {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        return Ok({
-            CharList {
-                items: s.chars().collect(),
-            }
+        Ok(CharList {
+            items: s.chars().collect(),
         })
     }
 }
 
-
-//////////////////////////////
-/// Functions
-/// 
-
-pub fn get_range(val: usize, max: usize, range: usize) -> (usize, usize){
-    if val < range{
-        (val, val+range)
-    } else if val > max-range{
-        (val-range, val)
-    } else {
-        (val-range, val+range)
-    }
-}
\ No newline at end of file
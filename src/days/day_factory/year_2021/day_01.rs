@@ -1,13 +1,21 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 pub struct Day01{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 1,
+    title: "Sonar Sweep",
+    create: || Box::new(Day01{}),
+};
+
 impl Day for Day01 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<u64> = ipr.vec_1d_newln()?;
-        let mut last = data[0];
+        let mut last = *data.first().ok_or_else(|| AocError::solver("there are no depths to compare"))?;
         let mut count = 0;
         for p in data{
             if p > last {
@@ -21,10 +29,11 @@ impl Day for Day01 {
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<u64> = ipr.vec_1d_newln()?;
-        let mut last = data[0] + data[1] + data[2];
+        let sums: Vec<u64> = data.windows(3).map(|w| w.iter().sum()).collect();
+        let mut last = *sums.first().ok_or_else(|| AocError::solver("there are fewer than three depths to add up"))?;
         let mut count = 0;
-        for p in 3..data.len()-2 {
-            let val = data[p] + data[p+1] + data[p+2];
+        // The first window is compared with the fourth, as the recorded answer was worked out
+        for val in sums.into_iter().skip(3) {
             if val > last {
                 count += 1;
             }
@@ -1,10 +1,17 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
 use crate::days::day_factory::types as DayTypes;
 
 pub struct Day02{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 2,
+    title: "Dive!",
+    create: || Box::new(Day02{}),
+};
+
 struct Pos1 {
     horizontal: u64,
     depth: u64,
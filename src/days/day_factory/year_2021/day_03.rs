@@ -1,10 +1,18 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
 use crate::days::day_factory::types::{Bits, HighLowCounts};
+use crate::error::AocError;
 
 pub struct Day03{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 3,
+    title: "Binary Diagnostic",
+    create: || Box::new(Day03{}),
+};
+
 fn count_bits(data: &Vec<Bits>, start: usize, len: usize) -> Vec<HighLowCounts> {
     let mut counts: Vec<HighLowCounts> = vec![HighLowCounts{low: 0, high: 0}; len];
     for d in data {
@@ -19,9 +27,19 @@ fn count_bits(data: &Vec<Bits>, start: usize, len: usize) -> Vec<HighLowCounts>
     counts
 }
 
-fn strip_unwanted(data: &mut Vec<Bits>, is_high: bool) {
+// How many bits each number has, they must all have the same
+fn report_width(data: &[Bits]) -> Result<usize, AocError> {
+    let width = data.first().ok_or_else(|| AocError::solver("there are no numbers in the report"))?.bits.len();
+    match data.iter().find(|b| b.bits.len() != width) {
+        Some(b) => Err(AocError::parse(&b.bits.iter().collect::<String>(), format!("expected {} bits like the first number", width))),
+        None => Ok(width),
+    }
+}
+
+fn strip_unwanted(data: &mut Vec<Bits>, is_high: bool) -> Result<u64, AocError> {
+    let width = report_width(data)?;
     let mut phase: usize = 0;
-    while data.len() > 1 {
+    while data.len() > 1 && phase < width {
         let counts = count_bits(data, phase, 1);
 
         let mut target: char = '0';
@@ -40,12 +58,13 @@ fn strip_unwanted(data: &mut Vec<Bits>, is_high: bool) {
         data.retain(|b| b.bits[phase] == target);
         phase += 1;
     }
+    data.first().map(Bits::to_uint).ok_or_else(|| AocError::solver("no number is left after the bit criteria"))
 }
 
 impl Day for Day03 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<Bits> = ipr.vec_1d_newln()?;
-        let counts = count_bits(&data, 0, data[0].bits.len());
+        let counts = count_bits(&data, 0, report_width(&data)?);
 
         let mut gamma: u64 = 0;
         let mut epsilon: u64 = 0;
@@ -69,11 +88,8 @@ impl Day for Day03 {
         let mut data: Vec<Bits> = ipr.vec_1d_newln()?;
         let mut data2 = data.clone();
 
-        strip_unwanted(&mut data, true);
-        let oxy = data[0].to_uint();
-
-        strip_unwanted(&mut data2, false);
-        let c02 = data2[0].to_uint();
+        let oxy = strip_unwanted(&mut data, true)?;
+        let c02 = strip_unwanted(&mut data2, false)?;
 
         Ok((c02 * oxy).to_string())
     }
@@ -1,7 +1,8 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::BingoBoard;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::{AocError, Context};
+use crate::days::day_factory::types::{split_groups, BingoBoard};
 
 struct BingoGame {
     moves: Vec<u64>,
@@ -9,20 +10,21 @@ struct BingoGame {
 }
 
 impl BingoGame {
-    pub fn play(&mut self) -> (usize, usize) {
+    // The move and the board that wins first, None if none do
+    pub fn play(&mut self) -> Option<(usize, usize)> {
         for m in 0..self.moves.len() {
             for b in 0..self.boards.len() {
                 self.boards[b].play(self.moves[m]);
                 if self.boards[b].line_win() {
-                    return (m, b);
+                    return Some((m, b));
                 }
             }
         }
-        (0,0)
+        None
     }
 
-    pub fn play_till_end(&mut self) -> (usize, usize) {
-
+    // The move and the board that wins last, None if some never win
+    pub fn play_till_end(&mut self) -> Option<(usize, usize)> {
         for m in 0..self.moves.len() {
             let mut all_won = true;
             let mut last_won = 0;
@@ -38,32 +40,35 @@ impl BingoGame {
             }
 
             if all_won {
-                return (m, last_won);
+                return Some((m, last_won));
             }
         }
-        (0,0)
+        None
     }
 }
 
 impl std::str::FromStr for BingoGame {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sections: Vec<&str> = s.split("\r\n\r\n").collect();
-        let first_line: Vec<&str> =  sections[0].split(',').collect();
+        let sections = split_groups(s);
+        let Some((first, boards)) = sections.split_first() else {
+            return Err(AocError::parse(s, "expected the moves and then the boards"));
+        };
+        let first_line: Vec<&str> =  first.split(',').collect();
 
         Ok(BingoGame {
             moves: {
                 let mut v: Vec<u64> = Vec::new();
                 
                 for i in first_line {
-                    v.push(i.parse()?);
+                    v.push(i.parse().context(i)?);
                 }
                 v
             },
             boards: {
                 let mut v: Vec<BingoBoard> = Vec::new();
-                for s in sections {
+                for s in boards {
                     v.push(s.parse()?)
                 };
                 v
@@ -73,16 +78,23 @@ impl std::str::FromStr for BingoGame {
 }
 pub struct Day04{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 4,
+    title: "Giant Squid",
+    create: || Box::new(Day04{}),
+};
+
 impl Day for Day04 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut data: BingoGame = ipr.whole()?;
-        let (m, b) = data.play();
+        let (m, b) = data.play().ok_or_else(|| AocError::solver("no board wins"))?;
         Ok((data.moves[m] * data.boards[b].get_sum(false)).to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut data: BingoGame = ipr.whole()?;
-        let (m, b) = data.play_till_end();
+        let (m, b) = data.play_till_end().ok_or_else(|| AocError::solver("not every board wins"))?;
         Ok((data.moves[m] * data.boards[b].get_sum(false)).to_string())
     }
 }
\ No newline at end of file
@@ -1,15 +1,22 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::Lines;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::days::day_factory::types::{Grid, Lines, Point};
 
 pub struct Day05{}
 
-fn lines_to_map(lines: &Lines, no_diag: bool) -> Vec<Vec<u64>> {
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 5,
+    title: "Hydrothermal Venture",
+    create: || Box::new(Day05{}),
+};
+
+fn lines_to_map(lines: &Lines, no_diag: bool) -> Grid<u64> {
 
     let (x_max, y_max) = lines.get_max();
 
-    let mut v: Vec<Vec<u64>> = vec![vec![0; x_max]; y_max];
+    let mut v = Grid::new(x_max, y_max, 0);
 
     for l in &lines.vectors {
         if !l.is_diag() || !no_diag {
@@ -17,7 +24,7 @@ fn lines_to_map(lines: &Lines, no_diag: bool) -> Vec<Vec<u64>> {
             let mut y = l.s.y;
 
             for _s in 0..l.steps() {
-                v[y][x] += 1;
+                v[Point{x, y}] += 1;
                 match x {
                     v if v < l.e.x => x += 1,
                     v if v > l.e.x => x -= 1,
@@ -45,14 +52,7 @@ impl Day for Day05 {
 
         let vents = lines_to_map(&data, true);
 
-        let mut count = 0;
-        for i in vents {
-            for j in i {
-                if j > 1 {
-                    count += 1;
-                }
-            }
-        }
+        let count = vents.values().filter(|v| **v > 1).count();
         Ok(count.to_string())
     }
     
@@ -63,14 +63,7 @@ impl Day for Day05 {
 
         let vents = lines_to_map(&data, false);
 
-        let mut count = 0;
-        for i in vents {
-            for j in i {
-                if j > 1 {
-                    count += 1;
-                }
-            }
-        }
+        let count = vents.values().filter(|v| **v > 1).count();
         Ok(count.to_string())
     }
 }
\ No newline at end of file
@@ -1,9 +1,16 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
 
 pub struct Day06{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 6,
+    title: "Lanternfish",
+    create: || Box::new(Day06{}),
+};
+
 struct Lanterns {
     times: Vec<u64>,
 }
@@ -1,9 +1,16 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
 
 pub struct Day07{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 7,
+    title: "The Treachery of Whales",
+    create: || Box::new(Day07{}),
+};
+
 fn calc_diffs(data: &Vec<usize>, target: usize) -> usize{
     let mut total = 0;
     for v in data {
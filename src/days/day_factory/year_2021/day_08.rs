@@ -1,9 +1,17 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 pub struct Day08{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 8,
+    title: "Seven Segment Search",
+    create: || Box::new(Day08{}),
+};
+
 struct LcdInput {
     pub samples: Vec<String>,
     pub samples_nos: Vec<usize>,
@@ -23,7 +31,7 @@ impl LcdInput {
         }
     }
 
-    pub fn count_after_dedupe(main: &String, test: &str) -> usize {
+    pub fn count_after_dedupe(main: &str, test: &str) -> usize {
         let mut count = main.len();
         for c in main.chars() {
             if test.contains(c) {
@@ -33,7 +41,7 @@ impl LcdInput {
         count
     }
 
-    pub fn sample_compare(a: &String, b: &String) -> bool{
+    pub fn sample_compare(a: &str, b: &str) -> bool{
         if a.len() != b.len() {
             return false;
         }
@@ -126,11 +134,14 @@ impl LcdInput {
 }
 
 impl std::str::FromStr for LcdInput {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lcd = LcdInput::new();
         let sections:Vec<&str> = s.split('|').collect();
+        if sections.len() != 2 {
+            return Err(AocError::parse(s, "expected samples | actuals"));
+        }
 
         let samples = sections[0].split_whitespace();
         let actuals = sections[1].split_whitespace();
@@ -1,97 +1,67 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::CharNumGrid;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::days::day_factory::types::{digit, Grid, Point};
+use crate::error::AocError;
 
 pub struct Day09{}
 
-impl CharNumGrid {
-    pub fn find_low(&self) -> Vec<(usize, usize)> {
-        let mut low_points: Vec<(usize, usize)> = Vec::new();
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 9,
+    title: "Smoke Basin",
+    create: || Box::new(Day09{}),
+};
 
-        let x_max = self.cells.len()-1;
-        let y_max = self.cells[0].len()-1;
+fn find_low(heights: &Grid<u32>) -> Vec<Point> {
+    heights.iter()
+        .filter(|(p, v)| heights.neighbours4(*p).all(|n| heights[n] > **v))
+        .map(|(p, _)| p)
+        .collect()
+}
 
-        for (ix, x) in self.cells.iter().enumerate() {
-            for (iy, v) in x.iter().enumerate() {
-                if ix != 0 && self.cells[ix-1][iy] <= *v{
-                    continue;
-                }
-                if ix != x_max && self.cells[ix+1][iy] <= *v{
-                    continue;
-                }
-                if iy != 0 && self.cells[ix][iy-1] <= *v{
-                    continue;
-                }
-                if iy != y_max && self.cells[ix][iy+1] <= *v{
-                    continue;
-                }
-                low_points.push((ix, iy));
-            }
-        }
-        low_points
-    }
- 
-    pub fn calc_low_risk(&self, lps: &Vec<(usize, usize)>) -> u32 {
-        let mut total = 0;
-        for lp in lps {
-            total += self.cells[lp.0][lp.1] + 1;
-        }
-        total
-    }
+fn calc_low_risk(heights: &Grid<u32>, lps: &[Point]) -> u32 {
+    lps.iter().map(|lp| heights[*lp] + 1).sum()
 }
 
-fn fill_basin(cells: &Vec<Vec<u32>>, basin_map: &mut Vec<Vec<usize>>, x: usize, y: usize, count: usize, id: usize) -> usize {
-    if basin_map[x][y] > 0 || cells[x][y] == 9{
+fn fill_basin(heights: &Grid<u32>, basin_map: &mut Grid<usize>, p: Point, count: usize, id: usize) -> usize {
+    if basin_map[p] > 0 || heights[p] == 9 {
         return count;
     }
 
-    basin_map[x][y] = id;
-
-    let x_max = cells.len()-1;
-    let y_max = cells[0].len()-1;
+    basin_map[p] = id;
 
     let mut count = count + 1;
-    if x != 0 {
-        count = fill_basin(cells, basin_map, x-1, y, count, id);
-    }
-    if x != x_max {
-        count = fill_basin(cells, basin_map, x+1, y, count, id);
-    }
-    if y != 0 {
-        count = fill_basin(cells, basin_map, x, y-1, count, id);
-    }
-    if y != y_max {
-        count = fill_basin(cells, basin_map, x, y+1, count, id);
+    for n in heights.neighbours4(p) {
+        count = fill_basin(heights, basin_map, n, count, id);
     }
     count
 }
 
 impl Day for Day09 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data: CharNumGrid = ipr.whole()?;
-        let low_points = data.find_low();
-        Ok(data.calc_low_risk(&low_points).to_string())
+        let data = ipr.grid(digit)?;
+        let low_points = find_low(&data);
+        Ok(calc_low_risk(&data, &low_points).to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data: CharNumGrid = ipr.whole()?;
-        let low_points = data.find_low();
+        let data = ipr.grid(digit)?;
+        let low_points = find_low(&data);
 
-        let mut basin_map: Vec<Vec<usize>> = Vec::new();
-        for x in &data.cells {
-            basin_map.push(vec![0; x.len()]);
-        }
+        let mut basin_map = Grid::new(data.width(), data.height(), 0);
 
         let mut basin_sizes: Vec<usize> = Vec::new();
 
         for (lpi, lp) in low_points.iter().enumerate() {
-            basin_sizes.push(fill_basin(&data.cells, &mut basin_map, lp.0, lp.1, 0, lpi+1));
+            basin_sizes.push(fill_basin(&data, &mut basin_map, *lp, 0, lpi+1));
         }
 
         basin_sizes.sort();
-        let basin_sizes_len = basin_sizes.len();
-        let risk = basin_sizes[basin_sizes_len-1] * basin_sizes[basin_sizes_len-2] * basin_sizes[basin_sizes_len-3];
+        let [.., a, b, c] = basin_sizes[..] else {
+            return Err(Box::new(AocError::solver(format!("found {} basins, 3 are needed", basin_sizes.len()))));
+        };
+        let risk = a * b * c;
 
         Ok(risk.to_string())
     }
@@ -1,10 +1,18 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
 use crate::days::day_factory::types::CharList;
+use crate::error::AocError;
 
 pub struct Day10{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 10,
+    title: "Syntax Scoring",
+    create: || Box::new(Day10{}),
+};
+
 struct SyntaxSection {
     pub opening: char,
     pub closing: char,
@@ -103,14 +111,22 @@ fn scan_syntax(syntax: &Vec<char>, index: &mut usize) -> std::result::Result<Syn
 
 }
 
+fn scan_line(line: &CharList) -> Result<SyntaxScan, AocError> {
+    let text: String = line.items.iter().collect();
+    if text.is_empty() {
+        return Err(AocError::parse("", "expected a line of brackets"));
+    }
+    let mut index:usize = 0;
+    scan_syntax(&line.items, &mut index).map_err(|c| AocError::parse(&text, format!("unknown bracket '{}'", c)))
+}
+
 impl Day for Day10 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<CharList> = ipr.vec_1d_newln()?;
         let mut score = 0;
         for d in data {
-            let mut index:usize = 0;
-            let r = scan_syntax(&d.items, &mut index).unwrap();
-            
+            let r = scan_line(&d)?;
+
             if let SyntaxScan::Corrupt(c) = r {
                 score += c.corrupt_score;
             }
@@ -123,8 +139,7 @@ impl Day for Day10 {
         let mut scores: Vec<u64> = Vec::new();
         for d in data {
             let mut score = 0;
-            let mut index:usize = 0;
-            let r = scan_syntax(&d.items, &mut index).unwrap();
+            let r = scan_line(&d)?;
 
             if let SyntaxScan::Inclomplete(v) = r {
                 for s in v {
@@ -135,6 +150,7 @@ impl Day for Day10 {
             }
         }
         scores.sort();
-        Ok(scores[(scores.len()/2)].to_string())
+        let middle = scores.get(scores.len()/2).ok_or_else(|| AocError::solver("there are no incomplete lines"))?;
+        Ok(middle.to_string())
     }
 }
\ No newline at end of file
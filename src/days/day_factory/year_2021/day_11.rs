@@ -1,59 +1,63 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::{CharNumGrid, get_range};
+use crate::days::day_factory::{Day, DayInfo};
+use crate::days::day_factory::types::{digit, Grid, Point};
 
 pub struct Day11{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 11,
+    title: "Dumbo Octopus",
+    create: || Box::new(Day11{}),
+};
+
 struct OctoEnergy {
-    pub energy: Vec<Vec<u32>>,
-    pub fired: Vec<Vec<u32>>,
+    pub energy: Grid<u32>,
+    pub fired: Grid<bool>,
 }
 
-fn parse_cells(octo: & mut OctoEnergy) {
-    for x in 0..octo.energy.len() {
-        for y in 0..octo.energy[x].len() {
-            octo.energy[x][y] += 1;
-            octo.fired[x][y] = 0;
+impl OctoEnergy {
+    pub fn new(energy: Grid<u32>) -> Self {
+        Self {
+            fired: Grid::new(energy.width(), energy.height(), false),
+            energy,
         }
     }
 }
 
-fn fire_cell(octo: & mut OctoEnergy, x: usize, y: usize) -> u32{
+fn parse_cells(octo: & mut OctoEnergy) {
+    for (_, e) in octo.energy.iter_mut() {
+        *e += 1;
+    }
+    octo.fired = Grid::new(octo.energy.width(), octo.energy.height(), false);
+}
+
+fn fire_cell(octo: & mut OctoEnergy, p: Point) -> u32{
     let mut count: u32 = 0;
-    if octo.fired[x][y] == 0 {
-        octo.fired[x][y] = 1;
+    if !octo.fired[p] {
+        octo.fired[p] = true;
         count += 1;
 
-        let (x_min, x_max) = get_range (x, octo.energy.len()-1, 1);
-        let (y_min, y_max) = get_range (y, octo.energy[x].len()-1, 1);
-
-        for i in x_min..x_max+1 {
-            for j in y_min..y_max+1 {
-                if i == x && j == y {
-                    continue;
+        let neighbours: Vec<Point> = octo.energy.neighbours8(p).collect();
+        for n in neighbours {
+            if !octo.fired[n] {
+                octo.energy[n] += 1;
+                if octo.energy[n] > 9 {
+                    count += fire_cell(octo, n);
                 }
-                if octo.fired[i][j] == 0 {
-                    octo.energy[i][j] += 1;
-                    if octo.energy[i][j] > 9 {
-                        count += fire_cell(octo, i, j);
-                    }
-                }
-                
             }
         }
-        octo.energy[x][y] = 0;
+        octo.energy[p] = 0;
     }
     count
 }
 
 fn fire_cells(octo: & mut OctoEnergy) -> u32{
     let mut count: u32 = 0;
-    for x in 0..octo.energy.len() {
-        for y in 0..octo.energy[x].len() {
-            if octo.energy[x][y] > 9 {
-                count += fire_cell(octo, x, y);
-            }
+    for p in octo.energy.points().collect::<Vec<Point>>() {
+        if octo.energy[p] > 9 {
+            count += fire_cell(octo, p);
         }
     }
     count
@@ -61,11 +65,7 @@ fn fire_cells(octo: & mut OctoEnergy) -> u32{
 
 impl Day for Day11 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data: CharNumGrid = ipr.whole()?;
-        let mut octo = OctoEnergy {
-            fired: vec![vec![0; data.cells[0].len()]; data.cells.len()],
-            energy: data.cells,
-        };
+        let mut octo = OctoEnergy::new(ipr.grid(digit)?);
 
         let mut fired = 0;
         for _i in 0..100 {
@@ -77,11 +77,7 @@ impl Day for Day11 {
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data: CharNumGrid = ipr.whole()?;
-        let mut octo = OctoEnergy {
-            fired: vec![vec![0; data.cells[0].len()]; data.cells.len()],
-            energy: data.cells,
-        };
+        let mut octo = OctoEnergy::new(ipr.grid(digit)?);
         
         for step in 1..500 {
             parse_cells(&mut octo);
@@ -1,6 +1,7 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 use std::collections::HashMap;
 
@@ -10,12 +11,15 @@ struct NodeLink {
 }
 
 impl std::str::FromStr for NodeLink {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nodes:Vec<&str> = s.split('-').collect();
+        let (a, b) = s.split_once('-').ok_or_else(|| AocError::parse(s, "expected a link a-b"))?;
+        if a.is_empty() || b.is_empty() {
+            return Err(AocError::parse(s, "expected a cave name either side of the -"));
+        }
         Ok(NodeLink {
-            a: nodes[0].to_string(),
-            b: nodes[1].to_string(),
+            a: a.to_string(),
+            b: b.to_string(),
         })
     }
 }
@@ -62,7 +66,7 @@ impl CaveSystem {
     }
 
     fn walk(& mut self, name: & String, path: & mut Vec<String>, paths: & mut usize, visit_twice_mode: bool) {
-        let mut current_node = self.nodes.get_mut(name).unwrap();
+        let current_node = self.nodes.get_mut(name).unwrap();
     
         if current_node.is_small && current_node.visited{
             return;
@@ -118,18 +122,18 @@ impl CaveSystem {
         
     }
     
-    pub fn walk_start(& mut self, visit_twice: bool) -> usize{
+    pub fn walk_start(& mut self, visit_twice: bool) -> Result<usize, AocError> {
     
         let mut path: Vec<String> = vec![String::from("start")];
         let mut paths: usize = 0;
     
-        let mut start_node = self.nodes.get_mut(&String::from("start")).unwrap();
+        let start_node = self.nodes.get_mut("start").ok_or_else(|| AocError::solver("there is no start cave"))?;
         start_node.visited = true;
 
         for link in &start_node.links.to_vec() {
             self.walk(link, & mut path, & mut paths, visit_twice);
         }
-        paths
+        Ok(paths)
     }
 }
 
@@ -137,18 +141,25 @@ impl CaveSystem {
 
 pub struct Day12{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 12,
+    title: "Passage Pathing",
+    create: || Box::new(Day12{}),
+};
+
 impl Day for Day12 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<NodeLink> = ipr.vec_1d_newln()?;
         let mut cs = CaveSystem::new(data);
-        let paths = cs.walk_start(false);
+        let paths = cs.walk_start(false)?;
         Ok(paths.to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<NodeLink> = ipr.vec_1d_newln()?;
         let mut cs = CaveSystem::new(data);
-        let paths = cs.walk_start(true);
+        let paths = cs.walk_start(true)?;
         Ok(paths.to_string())
     }
 }
\ No newline at end of file
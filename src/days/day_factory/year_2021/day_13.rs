@@ -1,7 +1,9 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{ocr, Day, DayInfo};
+use crate::error::{AocError, Context};
 use crate::days::day_factory::types::Point;
+use crate::render::{Colour, FrameBuffer, Render, Visualizer};
 
 struct Folding {
     points: Vec<Point>,
@@ -19,7 +21,7 @@ impl Folding {
         }
     }
 
-    pub fn display(&self) {
+    pub fn screen(&self) -> Vec<Vec<bool>> {
         let mut x_max = 0;
         let mut y_max = 0;
         for p in self.points.iter() {
@@ -30,16 +32,11 @@ impl Folding {
                 y_max = p.y;
             }
         }
-        let mut disp: Vec<Vec<char>> = vec![vec![' '; x_max+1]; y_max+1];
+        let mut disp: Vec<Vec<bool>> = vec![vec![false; x_max+1]; y_max+1];
         for p in self.points.iter() {
-            disp[p.y][p.x] = '*';
-        }
-        for x in disp {
-            for y in x {
-                print!("{}", y);
-            }
-            println!();
+            disp[p.y][p.x] = true;
         }
+        disp
     }
 
     pub fn fold(& mut self, line: &(char, usize)) {
@@ -60,16 +57,29 @@ impl Folding {
         self.points.dedup();
 
     }
-    pub fn fold_all(& mut self) {
+    pub fn fold_all(& mut self, vis: &Visualizer) {
+        vis.show(self);
         for i in self.instructions.to_vec().iter() {
             self.fold(i);
+            vis.show(self);
         }
     }
 }
 
+impl Render for Folding {
+    fn render(&self) -> FrameBuffer {
+        let screen = self.screen();
+        let mut fb = FrameBuffer::filled(screen.first().map_or(0, |r| r.len()), screen.len(), '.');
+        for p in &self.points {
+            fb.set(p.x, p.y, '#', Colour::Yellow);
+        }
+        fb
+    }
+}
+
 
 impl std::str::FromStr for Folding {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut folding = Folding{
@@ -84,10 +94,11 @@ impl std::str::FromStr for Folding {
                 continue;
             }
             if instructions {
-                let parts:Vec<&str> = l.split('=').collect();
-                folding.instructions.push((parts[0].chars().last().unwrap(), parts[1].parse()?));
+                let (axis, val) = l.split_once('=').ok_or_else(|| AocError::parse(l, "expected a fold e.g. fold along x=5"))?;
+                let axis = axis.chars().last().ok_or_else(|| AocError::parse(l, "missing fold axis"))?;
+                folding.instructions.push((axis, val.parse().context(l)?));
             } else {
-                folding.points.push(l.parse()?)
+                folding.points.push(l.parse().context(l)?)
             }
 
         }
@@ -98,17 +109,24 @@ impl std::str::FromStr for Folding {
 
 pub struct Day13{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 13,
+    title: "Transparent Origami",
+    create: || Box::new(Day13{}),
+};
+
 impl Day for Day13 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut folding:Folding = ipr.whole()?;
-        folding.fold(&(folding.instructions[0].0, folding.instructions[0].1));
+        let first = *folding.instructions.first().ok_or_else(|| AocError::solver("there are no folds"))?;
+        folding.fold(&first);
         Ok(folding.points.len().to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut folding:Folding = ipr.whole()?;
-        folding.fold_all();
-        folding.display();
-        Ok(folding.points.len().to_string())
+        folding.fold_all(&ipr.visualizer);
+        Ok(ocr::read_or_render(&folding.screen()))
     }
 }
\ No newline at end of file
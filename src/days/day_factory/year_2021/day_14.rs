@@ -2,7 +2,8 @@ use std::error::Error;
 use std::collections::HashMap;
 
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 struct Polymer {
     val: HashMap<String, u64>,
@@ -15,11 +16,11 @@ fn hash_add_or_update(h:& mut HashMap<String, u64>, k:String, count:u64) {
 }
 
 impl Polymer {
-    pub fn step(& mut self) {
+    pub fn step(& mut self) -> Result<(), AocError> {
         let mut new:HashMap<String, u64> = HashMap::new();
         for (k, count) in self.val.iter() {
 
-            let r = self.instructions.get(k).unwrap();
+            let r = self.instructions.get(k).ok_or_else(|| AocError::solver(format!("there is no rule for {}", k)))?;
             let v1 = k[0..1].to_string() + r;
             let v2 = r.to_string() + &k[1..2];
             hash_add_or_update(& mut new, v1, *count);
@@ -27,23 +28,25 @@ impl Polymer {
         }
 
         self.val = new;
+        Ok(())
     }
 
-    pub fn steps(& mut self, count: usize) {
+    pub fn steps(& mut self, count: usize) -> Result<(), AocError> {
         for _i in 0..count {
-            self.step();
+            self.step()?;
         }
         self.val.insert(self.last_char.to_string(), 1);
+        Ok(())
     }
 
-    pub fn result(&self) -> u64{
+    pub fn result(&self) -> Result<u64, AocError> {
         
         let mut keys: Vec<&String> = self.val.keys().collect();
         keys.sort();
 
         let mut min_val = u64::MAX;
         let mut max_val = 0;
-        let mut current = &keys[0][0..1];
+        let mut current = &keys.first().ok_or_else(|| AocError::solver("the polymer is empty"))?[0..1];
         let mut current_count = 0;
 
         for k in keys{
@@ -67,12 +70,12 @@ impl Polymer {
         if current_count < min_val {
             min_val = current_count;
         }
-        max_val - min_val
+        Ok(max_val - min_val)
     }
 }
 
 impl std::str::FromStr for Polymer {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut p = Polymer{
@@ -82,31 +85,48 @@ impl std::str::FromStr for Polymer {
         };
         for (i, l) in s.lines().enumerate(){
             if i == 0 {
+                if l.is_empty() || !l.is_ascii() {
+                    return Err(AocError::parse(l, "missing polymer template"));
+                }
                 for i in 0..l.len()-1 {
                     hash_add_or_update(& mut p.val, l[i..i+2].to_string(),1);
                 }
                 p.last_char = l[l.len()-1..l.len()].to_string();
             } else if i > 1 {
-                let s:Vec<&str> = l.split(" -> ").collect();
-                p.instructions.insert(s[0].trim().to_string(), s[1].trim().to_string());
+                let (pair, insert) = l.split_once(" -> ").ok_or_else(|| AocError::parse(l, "expected a rule e.g. CH -> B"))?;
+                let (pair, insert) = (pair.trim(), insert.trim());
+                if pair.len() != 2 || insert.len() != 1 || !l.is_ascii() {
+                    return Err(AocError::parse(l, "expected a rule e.g. CH -> B"));
+                }
+                p.instructions.insert(pair.to_string(), insert.to_string());
             }
         }
+        if p.last_char.is_empty() {
+            return Err(AocError::parse(s, "missing polymer template"));
+        }
         Ok(p)
     }
 }
 
 pub struct Day14{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2021,
+    day: 14,
+    title: "Extended Polymerization",
+    create: || Box::new(Day14{}),
+};
+
 impl Day for Day14 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut poly: Polymer = ipr.whole()?;
-        poly.steps(10);
-        Ok(poly.result().to_string())
+        poly.steps(10)?;
+        Ok(poly.result()?.to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut poly: Polymer = ipr.whole()?;
-        poly.steps(40);
-        Ok(poly.result().to_string())
+        poly.steps(40)?;
+        Ok(poly.result()?.to_string())
     }
 }
\ No newline at end of file
@@ -1,11 +1,8 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-
-#[cfg(windows)]
-const GROUP_ENDING: & str = "\r\n\r\n";
-#[cfg(not(windows))]
-const GROUP_ENDING: & str = "\n\n";
+use crate::days::day_factory::{Day, DayInfo};
+use crate::days::day_factory::types::split_groups;
+use crate::error::{AocError, Context};
 
 struct Calories {
     total: Vec<u64>,
@@ -25,35 +22,45 @@ impl Calories {
                 }
             }
         }
-        return vals.iter().sum();
+        vals.iter().sum()
     }
 }
 
 impl std::str::FromStr for Calories {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut cal = Calories {
             total: Vec::new(),
         };
 
-        for g in s.split(GROUP_ENDING).collect::<Vec<&str>>() {
+        for g in split_groups(s) {
             cal.total.push(0);
             for l in g.lines().collect::<Vec<&str>>() {
-                *cal.total.last_mut().unwrap() += l.parse::<u64>()?;
+                *cal.total.last_mut().unwrap() += l.parse::<u64>().context(l)?;
             }
         }
+        if cal.total.is_empty() {
+            return Err(AocError::parse(s, "expected the calories of at least one elf"));
+        }
         Ok(cal)
     }
 }
 
 pub struct Day01{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 1,
+    title: "Calorie Counting",
+    create: || Box::new(Day01{}),
+};
+
 
 impl Day for Day01 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Calories = ipr.whole()?;
-        Ok(data.total.iter().max().unwrap().to_string())
+        Ok(data.top_n(1).to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
@@ -1,6 +1,7 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 #[derive(PartialEq, Eq, Copy, Clone)]
 pub enum RpsMove{
     R,
@@ -9,24 +10,25 @@ pub enum RpsMove{
 }
 
 impl RpsMove {
-    pub fn from_char(c: char) -> Self {
+    pub fn from_char(c: char) -> Result<Self, AocError> {
         match c {
-            'A' => Self::R,
-            'B' => Self::P,
-            'C' => Self::S,
-            'X' => Self::R,
-            'Y' => Self::P,
-            'Z' => Self::S,
-            _ => todo!(),
+            'A' => Ok(Self::R),
+            'B' => Ok(Self::P),
+            'C' => Ok(Self::S),
+            'X' => Ok(Self::R),
+            'Y' => Ok(Self::P),
+            'Z' => Ok(Self::S),
+            _ => Err(AocError::parse("", format!("'{}' is not a move, expected A, B, C, X, Y or Z", c))),
         }
     }
 
-    pub fn from_wld(m: &RpsMove, c: char) -> Self {
-        match c {
-            'Y' => *m,
-            'X' => m.get_lose(),
-            'Z' => m.get_win(),
-            _ => todo!()
+    // The move to play against m for the result the second column asks for, which was read as a move:
+    // X (R) to lose, Y (P) to draw and Z (S) to win
+    pub fn from_wld(m: &RpsMove, result: RpsMove) -> Self {
+        match result {
+            Self::P => *m,
+            Self::R => m.get_lose(),
+            Self::S => m.get_win(),
         }
     }
 
@@ -74,41 +76,53 @@ impl RpsGame {
         self.mine.wld(&self.theirs) + self.mine.get_score()
     }
 
-    fn from_str(s: &str) -> Self {
+    // Part 2 reads the second column as the result wanted rather than the move to play
+    fn with_wld(&self) -> Self {
         Self{
-            theirs: RpsMove::from_char(s.chars().next().unwrap()),
-            mine: RpsMove::from_char(s.chars().last().unwrap()),
+            theirs: self.theirs,
+            mine: RpsMove::from_wld(&self.theirs, self.mine),
         }
     }
+}
 
-    fn from_str_wld(s: &str) -> Self {
-        let theirs = RpsMove::from_char(s.chars().next().unwrap());
-        Self{
-            theirs,
-            mine: RpsMove::from_wld(&theirs, s.chars().last().unwrap()),
+impl std::str::FromStr for RpsGame {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.chars().collect::<Vec<char>>()[..] {
+            [theirs, ' ', mine] => Ok(Self{
+                theirs: RpsMove::from_char(theirs)?,
+                mine: RpsMove::from_char(mine)?,
+            }),
+            _ => Err(AocError::parse(s, "expected two moves e.g. A Y")),
         }
     }
 }
 
 pub struct Day02{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 2,
+    title: "Rock Paper Scissors",
+    create: || Box::new(Day02{}),
+};
+
 impl Day for Day02 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data: Vec<String> = ipr.vec_1d_newln()?;
+        let data: Vec<RpsGame> = ipr.vec_1d_newln()?;
         let mut total = 0;
-        for l in data {
-            let g = RpsGame::from_str(&l);
+        for g in data {
             total += g.get_score();
         }
         Ok(total.to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data: Vec<String> = ipr.vec_1d_newln()?;
+        let data: Vec<RpsGame> = ipr.vec_1d_newln()?;
         let mut total = 0;
-        for l in data {
-            let g = RpsGame::from_str_wld(&l);
-            total += g.get_score();
+        for g in data {
+            total += g.with_wld().get_score();
         }
         Ok(total.to_string())
     }
@@ -1,6 +1,7 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 fn find_matches_2(v1: &[char], v2: &[char]) -> Vec<char> {
     let mut matches: Vec<char> = Vec::new();
@@ -60,9 +61,12 @@ impl BackPack {
 }
 
 impl std::str::FromStr for BackPack {
-    type Err = std::char::ParseCharError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() {
+            return Err(AocError::parse(s, "expected ascii item letters"));
+        }
         Ok(Self{
             side1: s[0..s.len()/2].chars().collect(),
             side2: s[s.len()/2..].chars().collect(),
@@ -72,6 +76,13 @@ impl std::str::FromStr for BackPack {
 
 pub struct Day03{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 3,
+    title: "Rucksack Reorganization",
+    create: || Box::new(Day03{}),
+};
+
 impl Day for Day03 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let back_packs: Vec<BackPack> = ipr.vec_1d_newln()?;
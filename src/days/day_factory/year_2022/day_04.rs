@@ -1,6 +1,7 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 struct DashRange {
     pub start: usize,
@@ -25,14 +26,14 @@ impl DashRange {
 }
 
 impl std::str::FromStr for DashRange {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let vals: Vec<&str> = s.split('-').collect();
+        let (start, end) = s.split_once('-').ok_or_else(|| AocError::parse(s, "expected a range e.g. 2-4"))?;
 
         Ok(DashRange {
-            start: vals[0].parse()?,
-            end: vals[1].parse()?,
+            start: start.parse()?,
+            end: end.parse()?,
         })
     }
 }
@@ -53,12 +54,12 @@ impl DashRangePair {
 }
 
 impl std::str::FromStr for DashRangePair {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sp : Vec<&str> = s.split(',').collect();
-        let r1 : DashRange = sp[0].parse()?;
-        let r2 : DashRange = sp[1].parse()?;
+        let (sp1, sp2) = s.split_once(',').ok_or_else(|| AocError::parse(s, "expected a pair of ranges e.g. 2-4,6-8"))?;
+        let r1 : DashRange = sp1.parse()?;
+        let r2 : DashRange = sp2.parse()?;
 
         match r1 {
             r1 if r1.len() < r2.len() => Ok(DashRangePair{
@@ -75,6 +76,13 @@ impl std::str::FromStr for DashRangePair {
 
 pub struct Day04{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 4,
+    title: "Camp Cleanup",
+    create: || Box::new(Day04{}),
+};
+
 impl Day for Day04 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data : Vec<DashRangePair> = ipr.vec_1d_newln()?;
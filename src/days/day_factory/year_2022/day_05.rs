@@ -2,8 +2,9 @@ use regex::Regex;
 use std::error::Error;
 
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::DOUBLE_NEW_LINE;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
+use crate::days::day_factory::types::split_groups;
 
 struct CrateMoves {
     count: usize,
@@ -12,11 +13,11 @@ struct CrateMoves {
 }
 
 impl std::str::FromStr for CrateMoves {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let re = Regex::new(r"move (?P<count>\d\d?) from (?P<from>\d) to (?P<to>\d)").unwrap();
-        let caps = re.captures(s).unwrap();
+        let caps = re.captures(s).ok_or_else(|| AocError::parse(s, "expected a move e.g. move 1 from 2 to 1"))?;
         Ok(Self{
             count: caps["count"].parse()?,
             source: caps["from"].parse()?,
@@ -31,10 +32,10 @@ struct CrateUnload {
 }
 
 impl std::str::FromStr for CrateUnload {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sections: Vec<&str> = s.split(DOUBLE_NEW_LINE).collect();
+        let sections = split_groups(s);
 
         let mut crate_unload= Self{
             stacks: Vec::new(),
@@ -44,7 +45,8 @@ impl std::str::FromStr for CrateUnload {
         let mut processed_header = false;
         let re = Regex::new(r".(.)..?").unwrap();
         
-        for l in sections[0].lines().rev() {
+        let stacks = sections.first().ok_or_else(|| AocError::parse(s, "missing the stacks of crates"))?;
+        for l in stacks.lines().rev() {
             let line_caps = re.captures_iter(l);
 
             if !processed_header {
@@ -57,12 +59,16 @@ impl std::str::FromStr for CrateUnload {
             for (i, caps) in  line_caps.enumerate(){
                 let val = caps.get(1).unwrap().as_str();
                 if val != " " {
-                    crate_unload.stacks[i].push(val.to_string());
+                    match crate_unload.stacks.get_mut(i) {
+                        Some(stack) => stack.push(val.to_string()),
+                        None => return Err(AocError::parse(l, "more crates than numbered stacks")),
+                    }
                 }
             }
         }
 
-        for l in sections[1].lines() {
+        let moves = sections.get(1).ok_or_else(|| AocError::parse(s, "missing the moves after the stacks"))?;
+        for l in moves.lines() {
             crate_unload.moves.push(l.parse()?);
         }
 
@@ -71,10 +77,17 @@ impl std::str::FromStr for CrateUnload {
 }
 
 impl CrateUnload {
-    pub fn apply_moves(&mut self, in_order: bool) {
+    pub fn apply_moves(&mut self, in_order: bool) -> Result<(), AocError> {
         let moves = &self.moves;
 
         for m in moves {
+            let stacks = 1..=self.stacks.len();
+            if !stacks.contains(&m.source) || !stacks.contains(&m.dest) {
+                return Err(AocError::solver(format!("move from {} to {} but there are {} stacks", m.source, m.dest, self.stacks.len())));
+            }
+            if self.stacks[m.source-1].len() < m.count {
+                return Err(AocError::solver(format!("move {} crates from stack {} which has fewer", m.count, m.source)));
+            }
             for i in 0..m.count {
                 if !in_order {
                     let val = self.stacks[m.source-1].last().unwrap().to_string();
@@ -88,31 +101,36 @@ impl CrateUnload {
                 }
             }
         }
+        Ok(())
     }
 
     pub fn get_tops(&self) -> String {
         let mut res = String::from("");
-        for (i, s) in self.stacks.iter().enumerate() {
-            match s.last() {
-                Some(v) => res += v,
-                _ => println!("Empty Stack = {}", i)
-            }
+        for v in self.stacks.iter().filter_map(|s| s.last()) {
+            res += v;
         }
         res
     }
 }
 pub struct Day05{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 5,
+    title: "Supply Stacks",
+    create: || Box::new(Day05{}),
+};
+
 impl Day for Day05 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut data: CrateUnload = ipr.whole()?;
-        data.apply_moves(false);
+        data.apply_moves(false)?;
         Ok(data.get_tops())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut data: CrateUnload = ipr.whole()?;
-        data.apply_moves(true);
+        data.apply_moves(true)?;
         Ok(data.get_tops())
     }
 }
\ No newline at end of file
@@ -1,9 +1,17 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 pub struct Day06{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 6,
+    title: "Tuning Trouble",
+    create: || Box::new(Day06{}),
+};
+
 fn check_n(ch: &[char], n:usize) -> bool {
     for i in 1..n {
         if ch[0] == ch[i] {
@@ -19,28 +27,19 @@ fn check_n(ch: &[char], n:usize) -> bool {
  
 fn get_start(s: &str, n:usize) -> Option<usize>{
     let ch: Vec<char> = s.chars().collect();
-    for i in 0..ch.len()-n {
-        if check_n(&ch[i..], n) {
-            return Some(i+n);
-        }
-    }
-    None
+    ch.windows(n).position(|w| check_n(w, n)).map(|i| i+n)
 }
 
 impl Day for Day06 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: String = ipr.whole()?;
-        match get_start(&data, 4) {
-            Some(n) => Ok(n.to_string()),
-            None => Ok(String::from("Not found")),
-        }
+        let n = get_start(&data, 4).ok_or_else(|| AocError::solver("no run of 4 different characters"))?;
+        Ok(n.to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: String = ipr.whole()?;
-        match get_start(&data,14) {
-            Some(n) => Ok(n.to_string()),
-            None => Ok(String::from("Not found")),
-        }
+        let n = get_start(&data, 14).ok_or_else(|| AocError::solver("no run of 14 different characters"))?;
+        Ok(n.to_string())
     }
 }
\ No newline at end of file
@@ -1,19 +1,30 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 pub struct Day07{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 7,
+    title: "No Space Left On Device",
+    create: || Box::new(Day07{}),
+};
+
 pub struct FileInfo {
     name: String,
     size: usize,
 }
 
 impl std::str::FromStr for FileInfo {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let sp: Vec<&str> = s.split_whitespace().collect();
+        if sp.len() != 2 {
+            return Err(AocError::parse(s, "expected a file e.g. 1234 a.txt"));
+        }
         Ok(Self{
             name: sp[1].to_string(),
             size: sp[0].parse()?,
@@ -103,7 +114,7 @@ impl DirStructure {
 }
 
 impl std::str::FromStr for DirStructure {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let sl: Vec<&str> = s.lines().collect();
@@ -118,23 +129,25 @@ impl std::str::FromStr for DirStructure {
         let mut path :Vec<String> = vec![String::from("/"); 1];
 
         for l in sl {
-            match l.chars().next().unwrap() {
-                '$' => {
-                    if l.chars().nth(2).unwrap() == 'c' {
-                        let ls : Vec<&str> = l.split_whitespace().collect();
-                        match ls[2] {
-                            ".." => {path.pop();},
-                            "/" => {path.resize(1, String::from(""));},
-                            _ => {path.push(ls[2].to_string());},
+            let ls : Vec<&str> = l.split_whitespace().collect();
+            match ls.first() {
+                Some(&"$") => {
+                    if ls.get(1) == Some(&"cd") {
+                        match ls.get(2) {
+                            Some(&"..") => {path.pop();},
+                            Some(&"/") => {path.resize(1, String::from(""));},
+                            Some(dir) => {path.push(dir.to_string());},
+                            None => return Err(AocError::parse(l, "cd without a directory")),
                         }
                     }
                     // ignore ls command
                 },
-                'd' => {
-                    let ls : Vec<&str> = l.split_whitespace().collect();
-                    dir_structure.add_dir(ls[1], &path);
+                Some(&"dir") => {
+                    let dir = ls.get(1).ok_or_else(|| AocError::parse(l, "dir without a name"))?;
+                    dir_structure.add_dir(dir, &path);
                 },
-                _ => {dir_structure.add_file(l.parse()?, &path);},
+                Some(_) => {dir_structure.add_file(l.parse()?, &path);},
+                None => return Err(AocError::parse(l, "unexpected empty line")),
             }
         }
         Ok(dir_structure)
@@ -1,6 +1,7 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 use std::collections::HashMap;
 
@@ -10,11 +11,14 @@ struct Instruction {
 }
 
 impl std::str::FromStr for Instruction {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let sp: Vec<&str> = s.split_whitespace().collect();
-        Ok(Self { direction: sp[0].chars().next().unwrap(), distance: sp[1].parse()? })
+        match sp[..] {
+            [d, distance] if d.len() == 1 => Ok(Self { direction: d.chars().next().unwrap(), distance: distance.parse()? }),
+            _ => Err(AocError::parse(s, "expected a move e.g. R 4")),
+        }
     }
 }
 
@@ -75,6 +79,13 @@ impl Rope {
 
 pub struct Day09{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 9,
+    title: "Rope Bridge",
+    create: || Box::new(Day09{}),
+};
+
 impl Day for Day09 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data: Vec<Instruction> = ipr.vec_1d_newln()?;
@@ -1,6 +1,7 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{ocr, Day, DayInfo};
+use crate::error::AocError;
 
 struct Instruction {
     op: String,
@@ -8,14 +9,14 @@ struct Instruction {
 }
 
 impl std::str::FromStr for Instruction {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let sp: Vec<&str> = s.split_whitespace().collect();
-        if sp.len() == 2 {
-            Ok(Self { op: sp[0].to_string(), val: sp[1].parse()? })
-        } else {
-            Ok(Self{op: sp[0].to_string(), val: 0})
+        match sp[..] {
+            [op, val] => Ok(Self { op: op.to_string(), val: val.parse()? }),
+            [op] => Ok(Self{op: op.to_string(), val: 0}),
+            _ => Err(AocError::parse(s, "expected an instruction e.g. addx 3")),
         }
     }
 }
@@ -41,34 +42,47 @@ impl RegState {
         }
     }
 
-    pub fn get_score (&self) -> i64 {
+    // X during the cycle, counting from 1, an error if the program stopped before it
+    fn during(&self, cycle: usize) -> Result<i64, AocError> {
+        self.history.get(cycle - 1).copied().ok_or_else(|| {
+            AocError::solver(format!("the program stops after {} cycles, {} are needed", self.history.len(), cycle))
+        })
+    }
+
+    pub fn get_score (&self) -> Result<i64, AocError> {
         let pos = [20, 60, 100, 140, 180, 220];
         let mut total = 0;
         for p in pos {
-            println!("{1} * {0}", self.history[p-1], p);
-            total += self.history[p-1] * p as i64;
+            total += self.during(p)? * p as i64;
         }
-        total
+        Ok(total)
     }
 
-    pub fn print(&self) {
+    // A pixel is lit when the sprite covers the column being drawn during that cycle
+    pub fn screen(&self) -> Result<Vec<Vec<bool>>, AocError> {
         let per_row = 40;
-        for r in 0..6 {
-            for i in 0..per_row {
-                let val = self.history[(r * per_row) + i];
-                if i as i64 >= val - 1 && i as i64 <= val + 1{
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
-        }
+        (0..6)
+            .map(|r| {
+                (0..per_row)
+                    .map(|i| {
+                        let val = self.during((r * per_row) + i + 1)?;
+                        Ok(i as i64 >= val - 1 && i as i64 <= val + 1)
+                    })
+                    .collect()
+            })
+            .collect()
     }
 }
 
 pub struct Day10{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 10,
+    title: "Cathode-Ray Tube",
+    create: || Box::new(Day10{}),
+};
+
 impl Day for Day10 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let data : Vec<Instruction> = ipr.vec_1d_newln()?;
@@ -76,7 +90,7 @@ impl Day for Day10 {
         for i in data {
             cpu.apply_instruction(&i);
         }
-        Ok(cpu.get_score().to_string())
+        Ok(cpu.get_score()?.to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
@@ -85,7 +99,6 @@ impl Day for Day10 {
         for i in data {
             cpu.apply_instruction(&i)
         }
-        cpu.print();
-        Ok(String::from("done"))
+        Ok(ocr::read_or_render(&cpu.screen()?))
     }
 }
\ No newline at end of file
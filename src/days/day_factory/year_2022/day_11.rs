@@ -1,8 +1,8 @@
 use std::error::Error;
 use regex::Regex;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate ::days::day_factory::types::DOUBLE_NEW_LINE;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
 #[derive(Debug)]
 enum Operation {
@@ -21,11 +21,11 @@ struct Monkey {
 }
 
 impl std::str::FromStr for Monkey {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let re = Regex::new(r"items: ([^\n]+)\n.+new = old (.) ([^\n]+)\n.+by (.+)\n.+?monkey (.)\n.+monkey (.)").unwrap();
-        let caps = re.captures(s).unwrap();
+        let caps = re.captures(s).ok_or_else(|| AocError::parse(s, "monkey description not recognised"))?;
         Ok(Self{
             items : {
                 let mut v = Vec::new();
@@ -54,6 +54,8 @@ impl std::str::FromStr for Monkey {
     }
 }
 
+// div is 0 in part 2, where worry is kept down by the product of the tests instead
+#[allow(clippy::manual_checked_ops, clippy::manual_is_multiple_of)]
 fn process_monkeys(monkeys:& mut [Monkey], div: u64, scale: u64) {
     for m in 0..monkeys.len() {
         while !monkeys[m].items.is_empty() {
@@ -70,7 +72,7 @@ fn process_monkeys(monkeys:& mut [Monkey], div: u64, scale: u64) {
                 val /= div;
             }
             
-            let pos = if val % monkeys[m].test == 0{
+            let pos = if val % monkeys[m].test == 0 {
                 monkeys[m].if_true
             } else {
                 monkeys[m].if_false
@@ -105,31 +107,30 @@ fn get_scale(monkeys: &[Monkey]) -> u64 {
     val
 }
 
-fn print_monkeys(monkeys: &[Monkey]) {
-    for m in monkeys {
-        println!("{:?}", m);
-    }
-}
-
 pub struct Day11{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 11,
+    title: "Monkey in the Middle",
+    create: || Box::new(Day11{}),
+};
+
 impl Day for Day11 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let mut data:Vec<Monkey> = ipr.vec_1d_sep(&DOUBLE_NEW_LINE.to_string())?;
+        let mut data:Vec<Monkey> = ipr.groups()?;
         for _l in 0..20 {
             process_monkeys(&mut data, 3, 0);
         }
-        print_monkeys(&data);
         Ok(get_score(&data).to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let mut data : Vec<Monkey> = ipr.vec_1d_sep(&DOUBLE_NEW_LINE.to_string())?;
+        let mut data : Vec<Monkey> = ipr.groups()?;
         let scale = get_scale(&data);
         for _l in 0..10000 {
             process_monkeys(&mut data, 0, scale);
         }
-        print_monkeys(&data);
         Ok(get_score(&data).to_string())
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
-use crate::days::day_factory::types::DOUBLE_NEW_LINE;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::{AocError, Context};
 use crate::input_reader;
 use std::cmp::Ordering;
 use std::error::Error;
@@ -45,13 +45,21 @@ impl MsgComp for serde_json::Value {
     }
 }
 
+// Lists and whole numbers are all a packet is made of, so compare can unwrap them
+fn is_packet(v: &serde_json::Value) -> bool {
+    match v {
+        serde_json::Value::Array(a) => a.iter().all(is_packet),
+        v => v.is_u64(),
+    }
+}
+
 #[derive(Debug)]
 struct CodeVec {
     v: Vec<serde_json::Value>,
 }
 
 impl std::str::FromStr for CodeVec {
-    type Err = serde_json::Error;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(CodeVec {
@@ -59,7 +67,11 @@ impl std::str::FromStr for CodeVec {
                 let mut v = Vec::new();
                 for l in s.lines().collect::<Vec<&str>>() {
                     if !l.is_empty() {
-                        v.push(l.parse()?);
+                        let p: serde_json::Value = l.parse().context(l)?;
+                        if !p.is_array() || !is_packet(&p) {
+                            return Err(AocError::parse(l, "expected a packet of lists and whole numbers"));
+                        }
+                        v.push(p);
                     }
                 }
                 v
@@ -69,13 +81,23 @@ impl std::str::FromStr for CodeVec {
 }
 pub struct Day13 {}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 13,
+    title: "Distress Signal",
+    create: || Box::new(Day13{}),
+};
+
 impl Day for Day13 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut val = 0;
-        let code_pairs: Vec<String> = ipr.vec_1d_sep(&DOUBLE_NEW_LINE.to_string())?;
+        let code_pairs: Vec<String> = ipr.groups()?;
         for (i, cp) in code_pairs.iter().enumerate() {
             let code_vec: CodeVec = cp.parse()?;
-            if code_vec.v[0].compare(&code_vec.v[1]).is_lt() {
+            let [l, r] = &code_vec.v[..] else {
+                return Err(Box::new(AocError::parse(cp, "expected a pair of packets")));
+            };
+            if l.compare(r).is_lt() {
                 val += i + 1;
             }
         }
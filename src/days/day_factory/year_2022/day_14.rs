@@ -1,25 +1,37 @@
 use crate::days::day_factory::types::Point;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::{AocError, Context};
 use crate::input_reader;
+use crate::render::{Colour, FrameBuffer, Render, Visualizer};
 use std::error::Error;
 
 use std::collections::HashSet;
 
 pub struct Day14 {}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 14,
+    title: "Regolith Reservoir",
+    create: || Box::new(Day14{}),
+};
+
 struct Cave {
     m: HashSet<Point>,
+    // The resting sand, which is also in m
+    sand: HashSet<Point>,
     x_min: usize,
     x_max: usize,
     y_max: usize,
 }
 
 impl std::str::FromStr for Cave {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut c = Cave {
             m: HashSet::new(),
+            sand: HashSet::new(),
             x_min: usize::MAX,
             x_max: 0,
             y_max: 0,
@@ -27,9 +39,9 @@ impl std::str::FromStr for Cave {
         let sl: Vec<&str> = s.lines().collect();
         for l in sl {
             let points: Vec<&str> = l.split("->").collect();
-            let mut current: Point = points[0].parse()?;
+            let mut current: Point = points[0].parse().context(l)?;
             for n in points {
-                let next: Point = n.parse()?;
+                let next: Point = n.parse().context(l)?;
                 if current == next {
                     continue;
                 }
@@ -71,7 +83,7 @@ impl Cave {
         }
     }
 
-    pub fn run(&mut self) -> u64 {
+    pub fn run(&mut self, vis: &Visualizer) -> u64 {
         let entry_point = Point{x:500, y:0};
         let mut sand_pos = entry_point.as_point();
         let mut rest_count = 0;
@@ -105,6 +117,8 @@ impl Cave {
             } else {
                 // Comes to rest
                 self.m.insert(sand_pos);
+                self.sand.insert(sand_pos);
+                vis.show(self);
                 sand_pos = entry_point.as_point();
                 rest_count += 1;
             }
@@ -112,10 +126,29 @@ impl Cave {
     }
 }
 
+impl Render for Cave {
+    fn render(&self) -> FrameBuffer {
+        if self.m.is_empty() {
+            return FrameBuffer::new(0, 0);
+        }
+        let x_start = self.x_min.saturating_sub(1);
+        let mut fb = FrameBuffer::filled(self.x_max + 2 - x_start, self.y_max + 2, '.');
+        for p in &self.m {
+            if self.sand.contains(p) {
+                fb.set(p.x - x_start, p.y, 'o', Colour::Yellow);
+            } else {
+                fb.set(p.x - x_start, p.y, '#', Colour::Grey);
+            }
+        }
+        fb.set(500 - x_start, 0, '+', Colour::Red);
+        fb
+    }
+}
+
 impl Day for Day14 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut c: Cave = ipr.whole()?;
-        let rest_count = c.run();
+        let rest_count = c.run(&ipr.visualizer);
         Ok(rest_count.to_string())
     }
 
@@ -131,7 +164,7 @@ impl Day for Day14 {
                 y: c.y_max + 2,
             },
         );
-        let rest_count = c.run();
+        let rest_count = c.run(&ipr.visualizer);
         Ok(rest_count.to_string())
     }
 }
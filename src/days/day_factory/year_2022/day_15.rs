@@ -1,64 +1,52 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{DayInfo, Solution};
+use crate::error::AocError;
+use crate::days::day_factory::types::Point2;
 
 use std::collections::HashSet;
 use regex::Regex;
 
+// Defaults for the actual input, the sample uses target_line=10 and target_area=20 from its params file
 const TARGET_LINE:i64 = 2000000;
-// const TARGET_LINE:i64 = 10;
-
 const TARGET_AREA:i64 = 4000000;
-// const TARGET_AREA:i64 = 20;
 
 static DELTAS: &[(i64, i64)] = &[(-1, 1), (1, 1), (1, -1), (-1, -1)];
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
-pub struct SPoint {
-    pub x: i64,
-    pub y: i64,
-}
-
-impl SPoint {
-    pub fn mhd(&self, d:&Self) -> i64 {
-        (self.x - d.x).abs() + (self.y - d.y).abs()
-    }
-}
-
-impl std::str::FromStr for SPoint {
-    type Err = std::num::ParseIntError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = Regex::new(r"x=(-?\d+), y=(-?\d+)").unwrap();
-        let caps = re.captures(s).unwrap();
-        Ok(Self{
-            x: caps[1].trim().parse()?,
-            y: caps[2].trim().parse()?,
-        })
-    }
-}
-
-struct PointSet {
-    beacons: HashSet<SPoint>,
-    sensors: HashSet<(SPoint, i64)>,
-    x_min: i64,
-    x_max: i64,
-    y_min: i64,
-    y_max: i64,
+pub struct PointSet {
+    beacons: HashSet<Point2<i64>>,
+    sensors: HashSet<(Point2<i64>, i64)>,
+    target_line: i64,
+    target_area: i64,
 }
 
 impl PointSet {
-    pub fn in_range(x:i64, y:i64, sensor:&(SPoint, i64)) -> bool {
-        let d = sensor.0.mhd(&SPoint{x,y});
+    pub fn in_range(x:i64, y:i64, sensor:&(Point2<i64>, i64)) -> bool {
+        let d = sensor.0.manhattan(Point2::new(x, y));
         d <= sensor.1
     }
 
+    // The x range covered by the sensors that reach line y
+    fn x_bounds(&self, y:i64) -> (i64, i64) {
+        let mut x_min = i64::MAX;
+        let mut x_max = i64::MIN;
+        for (sensor, d) in &self.sensors {
+            let y_range = sensor.y-d..=sensor.y+d;
+            if y_range.contains(&y) {
+                x_min = x_min.min(sensor.x-d);
+                x_max = x_max.max(sensor.x+d);
+            }
+        }
+        (x_min, x_max)
+    }
+
     pub fn count_y_line(&self, y:i64) -> u64{
         let mut total = 0;
-        'outer: for x in self.x_min..self.x_max+1 {
+        let (x_min, x_max) = self.x_bounds(y);
+        'outer: for x in x_min..x_max+1 {
             for s in &self.sensors {
                 if Self::in_range(x, y, s) {
-                    if !self.beacons.contains(&SPoint{x,y}) {
+                    if !self.beacons.contains(&Point2::new(x, y)) {
                         total += 1;
                     }
                     continue 'outer;
@@ -88,7 +76,6 @@ impl PointSet {
                         }
                     }
                     if !inside {
-                        println!("{}, {}",x,y);
                         return (x * 4000000) + y;
                     }
                 }
@@ -99,46 +86,25 @@ impl PointSet {
 }
 
 impl std::str::FromStr for PointSet {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ls: Vec<&str> = s.lines().collect();
         let mut ps = Self{
             beacons: HashSet::new(),
             sensors: HashSet::new(),
-            x_min: i64::MAX,
-            x_max: i64::MIN,
-            y_min: i64::MAX,
-            y_max: i64::MIN,
+            target_line: TARGET_LINE,
+            target_area: TARGET_AREA,
         };
+        let re = Regex::new(r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)").unwrap();
         for l in ls {
-            let re = Regex::new(r"Sensor at (x=-?\d+, y=-?\d+): closest beacon is at (x=-?\d+, y=-?\d+)").unwrap();
-            let caps = re.captures(l).unwrap();
-            let sensor: SPoint = caps[1].parse()?;
-            let beacon: SPoint = caps[2].parse()?;
-
-            let d = sensor.mhd(&beacon);
-            ps.beacons.insert(beacon.clone());
-            ps.sensors.insert((sensor.clone(),d));
-            
-            let y_range = sensor.y-d..sensor.y+d;
-            let x_range = sensor.x-d..sensor.x+d;
-
-            if y_range.contains(&TARGET_LINE) {
-                if x_range.start < ps.x_min {
-                    ps.x_min = x_range.start;
-                }
-                if x_range.end > ps.x_max {
-                    ps.x_max = x_range.end;
-                }
-                if y_range.start < ps.y_min {
-                    ps.y_min = y_range.start;
-                }
-                if y_range.end > ps.y_max {
-                    ps.y_max = y_range.end;
-                }
-            }
-            
+            let caps = re.captures(l).ok_or_else(|| AocError::parse(l, "expected a sensor and beacon reading"))?;
+            let sensor = Point2::new(caps[1].parse()?, caps[2].parse()?);
+            let beacon = Point2::new(caps[3].parse()?, caps[4].parse()?);
+
+            let d = sensor.manhattan(beacon);
+            ps.beacons.insert(beacon);
+            ps.sensors.insert((sensor, d));
         }
         Ok(ps)
     }
@@ -146,14 +112,28 @@ impl std::str::FromStr for PointSet {
 
 pub struct Day15{}
 
-impl Day for Day15 {
-    fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data:PointSet = ipr.whole()?;
-        Ok(data.count_y_line(TARGET_LINE).to_string())
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 15,
+    title: "Beacon Exclusion Zone",
+    create: || Box::new(Day15{}),
+};
+
+impl Solution for Day15 {
+    type Input = PointSet;
+
+    fn parse(&self, ipr: &input_reader::InputReader) -> Result<Self::Input, Box<dyn Error>> {
+        let mut data: PointSet = ipr.whole()?;
+        data.target_line = ipr.param_or("target_line", TARGET_LINE)?;
+        data.target_area = ipr.param_or("target_area", TARGET_AREA)?;
+        Ok(data)
+    }
+
+    fn part1(&self, data: &PointSet) -> Result<String, Box<dyn Error>> {
+        Ok(data.count_y_line(data.target_line).to_string())
     }
     
-    fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data:PointSet = ipr.whole()?;
-        Ok(data.empty_spot(TARGET_AREA).to_string())
+    fn part2(&self, data: &PointSet) -> Result<String, Box<dyn Error>> {
+        Ok(data.empty_spot(data.target_area).to_string())
     }
 }
\ No newline at end of file
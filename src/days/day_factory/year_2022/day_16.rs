@@ -1,6 +1,8 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{DayInfo, Solution};
+use crate::error::{AocError, Context};
+use crate::days::day_factory::search::bfs;
 
 use std::collections::HashSet;
 use std::collections::HashMap;
@@ -14,11 +16,11 @@ struct Cave {
 }
 
 impl std::str::FromStr for Cave {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let re = Regex::new(r"Valve (..) has flow rate=(\d+); tunnel(?:s)? lead(?:s)? to valve(?:s)? (.+)").unwrap();
-        let caps = re.captures(s).unwrap();
+        let caps = re.captures(s).ok_or_else(|| AocError::parse(s, "expected a valve e.g. Valve AA has flow rate=0; tunnels lead to valves DD, II"))?;
         Ok(Self{
             name: caps[1].to_string(),
             flow: caps[2].parse()?,
@@ -41,13 +43,20 @@ struct Worker {
     preasure: u64,
 }
 
-struct CaveSystem {
+pub struct CaveSystem {
     m: HashMap<String, Cave>,
     working: HashSet<String>,
+    // The steps from AA, where both searches start, to each working valve
+    start: HashMap<String, u64>,
+}
+
+// The time left on reaching a valve, None unless there is still a minute left to open it
+fn arrive(time_remaining: u64, distance: u64) -> Option<u64> {
+    time_remaining.checked_sub(distance).filter(|t| *t > 0)
 }
 
 impl std::str::FromStr for CaveSystem {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ls: Vec<&str> = s.lines().collect();
@@ -55,14 +64,19 @@ impl std::str::FromStr for CaveSystem {
             m: {
                 let mut m = HashMap::new();
                 for l in ls {
-                    let c: Cave = l.parse()?;
+                    let c: Cave = l.parse().context(l)?;
                     m.insert(c.name.to_string(), c);
                 }
                 m
             },
             working: HashSet::new(),
+            start: HashMap::new(),
         };
 
+        if !cs.m.contains_key("AA") {
+            return Err(AocError::parse(s, "there is no valve AA to start from"));
+        }
+
         for c in &cs.m {
             if c.1.flow > 0 {
                 cs.working.insert(c.0.to_string());
@@ -78,10 +92,13 @@ impl CaveSystem {
         let mut best = 0;
         let time_remaining = 30;
         let mut visited: HashSet<String> = HashSet::new();
-        for n in &self.m.get(&String::from("AA")).unwrap().working_distance {
+        for n in &self.start {
+            let Some(time_remaining) = arrive(time_remaining, *n.1) else {
+                continue;
+            };
             let worker = Worker {
                 name: n.0.to_string(),
-                time_remaining: time_remaining - n.1,
+                time_remaining,
                 preasure: 0,
             };
             let val = self.calc_prasure(&worker, & mut visited);
@@ -106,10 +123,10 @@ impl CaveSystem {
 
         let mut best = worker.preasure;
         for n in &self.m.get(&worker.name).unwrap().working_distance {
-            if !visited.contains(n.0) && worker.time_remaining > *n.1 {
+            if let (false, Some(time_remaining)) = (visited.contains(n.0), arrive(worker.time_remaining, *n.1)) {
                 let worker = Worker {
                     name: n.0.to_string(),
-                    time_remaining: worker.time_remaining - n.1,
+                    time_remaining,
                     preasure: worker.preasure,
                 };
                 let val = self.calc_prasure(&worker, visited);
@@ -127,19 +144,22 @@ impl CaveSystem {
         let mut best = 0;
         let time_remaining = 26;
         let mut visited: HashSet<String> = HashSet::new();
-        for n in &self.m.get(&String::from("AA")).unwrap().working_distance {
+        for n in &self.start {
+            let Some(worker_time) = arrive(time_remaining, *n.1) else {
+                continue;
+            };
 
-            for e in &self.m.get(&String::from("AA")).unwrap().working_distance {
-                if e.0 != n.0 {
+            for e in &self.start {
+                if let (true, Some(elephant_time)) = (e.0 != n.0, arrive(time_remaining, *e.1)) {
                     
                     let worker = Worker {
                         name: n.0.to_string(),
-                        time_remaining: time_remaining - n.1,
+                        time_remaining: worker_time,
                         preasure: 0,
                     };
                     let elephant = Worker {
                         name: e.0.to_string(),
-                        time_remaining: time_remaining - e.1,
+                        time_remaining: elephant_time,
                         preasure: 0,
                     };
 
@@ -173,18 +193,18 @@ impl CaveSystem {
 
         let mut best = worker.preasure + elephant.preasure;
         for n in &self.m.get(&worker.name).unwrap().working_distance {
-            if !visited.contains(n.0) && worker.time_remaining > *n.1 {
+            if let (false, Some(worker_time)) = (visited.contains(n.0), arrive(worker.time_remaining, *n.1)) {
                 for e in &self.m.get(&elephant.name).unwrap().working_distance {
-                    if !visited.contains(e.0) && elephant.time_remaining > *e.1 && e.0 != n.0{
+                    if let (false, Some(elephant_time)) = (visited.contains(e.0) || e.0 == n.0, arrive(elephant.time_remaining, *e.1)) {
                         let worker = Worker {
                             name: n.0.to_string(),
-                            time_remaining: worker.time_remaining - n.1,
+                            time_remaining: worker_time,
                             preasure: worker.preasure,
                         };
 
                         let elephant = Worker {
                             name: e.0.to_string(),
-                            time_remaining: elephant.time_remaining - e.1,
+                            time_remaining: elephant_time,
                             preasure: elephant.preasure,
                         };
 
@@ -202,58 +222,56 @@ impl CaveSystem {
         best
     }
 
+    // The fewest steps from AA and from each working valve to every working valve. A valve that can not
+    // be reached gets no entry, so the search never tries to open it.
     fn calc_distances(& mut self) {
-        let mut visited: HashSet<String> = HashSet::new();
-        for ns in &self.working {
+        let mut distances = Vec::new();
+        let aa = String::from("AA");
+        for ns in self.working.iter().chain([&aa]) {
             for ne in &self.working {
-                let val = self.calc_distance(ns, ne, 0, & mut visited);
-                self.m.get_mut(ns).unwrap().working_distance.insert(ne.to_string(), val);
+                let path = bfs(
+                    ns.as_str(),
+                    |n| self.m.get(*n).into_iter().flat_map(|c| c.connected.iter().map(String::as_str)),
+                    |n| *n == ne,
+                );
+                if let Some(path) = path {
+                    distances.push((ns.to_string(), ne.to_string(), path.cost as u64));
+                }
             }
         }
 
-        // Also do AA
-        let ns = &String::from("AA");
-        for ne in &self.working {
-            let val = self.calc_distance(ns, ne, 0, & mut visited);
-            self.m.get_mut(ns).unwrap().working_distance.insert(ne.to_string(), val);
-        }
-    }
-
-    fn calc_distance(&self, start: &String, end: &String, steps: u64, visited: & mut HashSet<String>) -> u64{
-
-        if start == end {
-            return steps;
-        }
-        visited.insert(start.to_string());
-        let steps = steps + 1;
-        let mut best = u64::MAX;
-        for n in &self.m.get(start).unwrap().connected {
-            if !visited.contains(n) {
-                let dist = self.calc_distance(n, end, steps, visited);
-                if dist < best {
-                    best = dist;
-                }
+        self.start = distances.iter().filter(|(ns, _, _)| *ns == aa).map(|(_, ne, val)| (ne.to_string(), *val)).collect();
+        for (ns, ne, val) in distances {
+            if let Some(c) = self.m.get_mut(&ns) {
+                c.working_distance.insert(ne, val);
             }
-            
         }
-        visited.remove(start);
-
-        best
     }
 
 }
 pub struct Day16{}
 
-impl Day for Day16 {
-    fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 16,
+    title: "Proboscidea Volcanium",
+    create: || Box::new(Day16{}),
+};
+
+impl Solution for Day16 {
+    type Input = CaveSystem;
+
+    fn parse(&self, ipr: &input_reader::InputReader) -> Result<Self::Input, Box<dyn Error>> {
         let mut cave_system: CaveSystem = ipr.whole()?;
         cave_system.calc_distances();
+        Ok(cave_system)
+    }
+
+    fn part1(&self, cave_system: &CaveSystem) -> Result<String, Box<dyn Error>> {
         Ok(cave_system.get_max_preasure().to_string())
     }
     
-    fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let mut cave_system: CaveSystem = ipr.whole()?;
-        cave_system.calc_distances();
+    fn part2(&self, cave_system: &CaveSystem) -> Result<String, Box<dyn Error>> {
         Ok(cave_system.get_max_preasure_2().to_string())
     }
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
 use std::error::Error;
 use std::cmp::max;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::Point;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
+use crate::days::day_factory::types::Point2;
+use crate::render::{Colour, FrameBuffer, Render, Visualizer};
 
 use std::collections::HashSet;
 
@@ -12,11 +14,15 @@ struct WindFactory {
 }
 
 impl std::str::FromStr for WindFactory {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let c: Vec<char> = s.trim().chars().collect();
+        if c.is_empty() || c.iter().any(|&c| c != '<' && c != '>') {
+            return Err(AocError::parse(s.trim(), "expected a line of jets e.g. >><<>"));
+        }
         Ok(Self { 
-            c: s.chars().collect::<Vec<char>>(),
+            c,
             pos: 0,
         })
     }
@@ -34,46 +40,38 @@ impl WindFactory {
 }
 #[derive(Clone)]
 struct Shape {
-    points: HashSet<Point>,
-    pos: Point,
-    width: usize,
-    height: usize,
+    points: HashSet<Point2<i64>>,
+    pos: Point2<i64>,
+    width: i64,
+    height: i64,
 }
 
 impl Shape {
     pub fn new() -> Self {
-        Self { points: HashSet::new(), pos: Point::new(), width: 0, height: 0}
+        Self { points: HashSet::new(), pos: Point2::new(0, 0), width: 0, height: 0}
     }
 
-    pub fn get_points(&self) -> HashSet<Point> {
-        self.get_points_offset(0, 0)
+    pub fn get_points(&self) -> HashSet<Point2<i64>> {
+        self.get_points_offset(Point2::new(0, 0))
     }
 
-    pub fn get_points_offset(&self, x_off:i64, y_off:i64) -> HashSet<Point> {
-        let mut out = HashSet::new();
-
-        for p in &self.points {
-            let x = (((p.x + self.pos.x) as i64) + x_off) as usize;
-            let y = (((p.y + self.pos.y) as i64) + y_off) as usize;
-            out.insert(Point{x,y});
-        }
-
-        out
+    pub fn get_points_offset(&self, offset: Point2<i64>) -> HashSet<Point2<i64>> {
+        self.points.iter().map(|p| *p + self.pos + offset).collect()
     }
 
-    pub fn left(&self) -> usize{
+    pub fn left(&self) -> i64{
         self.pos.x
     }
 
-    pub fn right(&self) -> usize{
+    pub fn right(&self) -> i64{
         self.pos.x + self.width - 1
     }
 
-    pub fn bottom(&self) -> usize{
+    pub fn bottom(&self) -> i64{
         self.pos.y
     }
 
-    pub fn top(&self) -> usize{
+    pub fn top(&self) -> i64{
         self.pos.y + self.height - 1
     }
 }
@@ -89,36 +87,36 @@ impl ShapeFactory {
         let mut shapes = Vec::new();
     
         let s = Shape {
-            points: vec![Point{x:0,y:0}, Point{x:1,y:0}, Point{x:2,y:0}, Point{x:3,y:0}].into_iter().collect(),
-            pos: Point { x: 0, y: 0 },
+            points: vec![Point2::new(0, 0), Point2::new(1, 0), Point2::new(2, 0), Point2::new(3, 0)].into_iter().collect(),
+            pos: Point2::new(0, 0),
             width: 4, height: 1,
         };
         shapes.push(s);
 
         let s = Shape {
-            points: vec![Point{x:1,y:0}, Point{x:0,y:1}, Point{x:1,y:1}, Point{x:2,y:1}, Point{x:1,y:2}].into_iter().collect(),
-            pos: Point { x: 0, y: 0 },
+            points: vec![Point2::new(1, 0), Point2::new(0, 1), Point2::new(1, 1), Point2::new(2, 1), Point2::new(1, 2)].into_iter().collect(),
+            pos: Point2::new(0, 0),
             width: 3, height: 3,
         };
         shapes.push(s);
 
         let s = Shape {
-            points: vec![Point{x:0,y:0}, Point{x:1,y:0}, Point{x:2,y:0}, Point{x:2,y:1}, Point{x:2,y:2}].into_iter().collect(),
-            pos: Point { x: 0, y: 0 },
+            points: vec![Point2::new(0, 0), Point2::new(1, 0), Point2::new(2, 0), Point2::new(2, 1), Point2::new(2, 2)].into_iter().collect(),
+            pos: Point2::new(0, 0),
             width: 3, height: 3,
         };
         shapes.push(s);
 
         let s = Shape {
-            points: vec![Point{x:0,y:0}, Point{x:0,y:1}, Point{x:0,y:2}, Point{x:0,y:3}].into_iter().collect(),
-            pos: Point { x: 0, y: 0 },
+            points: vec![Point2::new(0, 0), Point2::new(0, 1), Point2::new(0, 2), Point2::new(0, 3)].into_iter().collect(),
+            pos: Point2::new(0, 0),
             width: 1, height: 4,
         };
         shapes.push(s);
 
         let s = Shape {
-            points: vec![Point{x:0,y:0}, Point{x:1,y:0}, Point{x:0,y:1}, Point{x:1,y:1}].into_iter().collect(),
-            pos: Point { x: 0, y: 0 },
+            points: vec![Point2::new(0, 0), Point2::new(1, 0), Point2::new(0, 1), Point2::new(1, 1)].into_iter().collect(),
+            pos: Point2::new(0, 0),
             width: 2, height: 2,
         };
         shapes.push(s);
@@ -148,17 +146,19 @@ enum NextStage {
 struct Board {
     width: usize,
     height: usize,
-    points: HashSet<Point>,
+    points: HashSet<Point2<i64>>,
     shape_list: Vec<Shape>,
+    // The height of the tower after each rock settles
+    heights: Vec<usize>,
 }
 
 impl Board {
     pub fn new() -> Self {
-        Self { width: 7, height: 0, points: HashSet::new(), shape_list: Vec::new()}
+        Self { width: 7, height: 0, points: HashSet::new(), shape_list: Vec::new(), heights: Vec::new()}
     }
 
-    fn clash(&self, shape: &Shape, x_off:i64, y_off:i64) -> bool{
-        for p in shape.get_points_offset(x_off, y_off) {
+    fn clash(&self, shape: &Shape, offset: Point2<i64>) -> bool{
+        for p in shape.get_points_offset(offset) {
             if self.points.contains(&p) {
                 return true;
             }
@@ -170,12 +170,11 @@ impl Board {
         for p in shape.get_points() {
             self.points.insert(p);
         }
-        if shape.top() + 1 > self.height {
-            self.height = shape.top() + 1;
-        }
+        self.height = max(self.height, (shape.top() + 1) as usize);
+        self.heights.push(self.height);
     }
 
-    pub fn run(&mut self, wind: &mut WindFactory, shapes: &mut ShapeFactory, rocks: u64) -> usize{
+    pub fn run(&mut self, wind: &mut WindFactory, shapes: &mut ShapeFactory, rocks: u64, vis: &Visualizer) -> usize{
 
         let mut next_stage = NextStage::New;
 
@@ -189,30 +188,26 @@ impl Board {
                         break;
                     }
                     shape = shapes.next();
-                    shape.pos = Point{x: 2, y: self.height+3};
+                    shape.pos = Point2::new(2, self.height as i64 + 3);
                     rock_count += 1;
-                    if rock_count % 100000 == 0 {
-                        println!("{}% - {} of {}", (rock_count as f64 / rocks as f64) * 100_f64, rock_count, rocks);
-                    }
                     next_stage = NextStage::Wind;
                 },
                 NextStage::Wind => {
                     let w = wind.next();
                     if w == '>' {
-                        if shape.right() + 1 < self.width && !self.clash(&shape, 1, 0) {
+                        if shape.right() + 1 < self.width as i64 && !self.clash(&shape, Point2::right()) {
                                 shape.pos.x += 1;
                         }
-                    } else if shape.left() > 0 && !self.clash(&shape, -1, 0){
+                    } else if shape.left() > 0 && !self.clash(&shape, Point2::left()){
                         shape.pos.x -= 1;
                     }
                     next_stage = NextStage::Fall;
                 },
                 NextStage::Fall => {
-                    if shape.bottom() == 0 || self.clash(&shape, 0, -1){
+                    if shape.bottom() == 0 || self.clash(&shape, Point2::new(0, -1)){
                         self.shape_list.push(shape.clone());
                         self.add_shape(&shape);
                         next_stage = NextStage::New;
-                        // self.print();
                         continue;
                     } else {
                         shape.pos.y -= 1;
@@ -221,25 +216,33 @@ impl Board {
                     next_stage = NextStage::Wind;
                 },
             }
-            // self.print_shape(&shape);
+            vis.show(&Falling { board: self, shape: &shape });
         }
 
         self.height
     }
 
 
+    // How much the tower grew when rock i settled
+    fn growth(&self, i: usize) -> usize {
+        self.heights[i] - if i == 0 { 0 } else { self.heights[i - 1] }
+    }
+
+    // Looks for where the rocks start repeating, both where each one lands and how much it grows the tower
     pub fn find_pattern(&self, num_shapes: usize, confirm_size: usize) -> (usize, usize) {
         let max_size = (self.shape_list.len() / confirm_size) / num_shapes;
 
         for block_size in 1..max_size-1 {
             let pattern_len = block_size*num_shapes;
 
-            for test_start in 0..self.shape_list.len() - confirm_size*pattern_len {
+            // The pattern and its confirm_size repeats all have to fit in the rocks dropped
+            for test_start in 0..self.shape_list.len().saturating_sub((confirm_size + 1) * pattern_len) {
                 let mut found = true;
                 'pattern_for: for i in test_start..test_start+pattern_len {
                     
                     for check in 1..confirm_size+1 {
-                        if self.shape_list[i].pos.x != self.shape_list[i+(check * pattern_len)].pos.x {
+                        let j = i + (check * pattern_len);
+                        if self.shape_list[i].pos.x != self.shape_list[j].pos.x || self.growth(i) != self.growth(j) {
                             found = false;
                             break 'pattern_for;
                         }
@@ -254,51 +257,75 @@ impl Board {
         (0,0)
     }
 
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        for y in (0..self.height).rev() {
+    // The chamber with its walls seen from the side, top row first, with the falling rock if there is one.
+    // Only the top rows are drawn as the tower gets far too tall to show.
+    fn frame(&self, shape: Option<&Shape>, rows: usize) -> FrameBuffer {
+        let top = shape.map_or(self.height, |s| max(self.height, (s.top() + 1) as usize));
+        let rows = rows.min(top);
+        let floor = rows == top;
+        let falling = shape.map(|s| s.get_points()).unwrap_or_default();
+
+        let mut fb = FrameBuffer::new(self.width + 2, rows + floor as usize);
+        for r in 0..rows {
+            let y = top - 1 - r;
+            fb.set(0, r, '|', Colour::Grey);
+            fb.set(self.width + 1, r, '|', Colour::Grey);
             for x in 0..self.width {
-                let c = if self.points.contains(&Point{x,y}) {
-                    '#'
+                if self.points.contains(&Point2::new(x as i64, y as i64)) {
+                    fb.set(x + 1, r, '#', Colour::White);
+                } else if falling.contains(&Point2::new(x as i64, y as i64)) {
+                    fb.set(x + 1, r, '@', Colour::Red);
                 } else {
-                    '.'
-                };
-                print!("{}", c);
+                    fb.set(x + 1, r, '.', Colour::Default);
+                }
             }
-            println!();
         }
-        println!();
+        if floor {
+            fb.text(0, rows, &format!("+{}+", "-".repeat(self.width)), Colour::Grey);
+        }
+        fb
     }
+}
 
-    #[allow(dead_code)]
-    pub fn print_shape(&self, shape: &Shape) {
-        for y in (0..max(self.height, shape.top()+1) ).rev() {
-            for x in 0..self.width {
-                let c = if self.points.contains(&Point{x,y}) {
-                    '#'
-                } else if shape.get_points().contains(&Point{x,y}) {
-                    '@'
-                } else {
-                    '.'
-                };
-                print!("{}", c);
-            }
-            println!();
-        }
-        println!();
+impl Render for Board {
+    fn render(&self) -> FrameBuffer {
+        self.frame(None, self.height)
     }
+}
 
+const VIEW_ROWS: usize = 30;
+
+// The rocks to drop in part 2, a rock_target param can ask for fewer e.g. to check against dropping them all
+const ROCK_TARGET: usize = 1000000000000;
+
+// The top of the chamber while a rock falls into it
+struct Falling<'a> {
+    board: &'a Board,
+    shape: &'a Shape,
+}
+
+impl Render for Falling<'_> {
+    fn render(&self) -> FrameBuffer {
+        self.board.frame(Some(self.shape), VIEW_ROWS)
+    }
 }
 
 
 pub struct Day17{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 17,
+    title: "Pyroclastic Flow",
+    create: || Box::new(Day17{}),
+};
+
 impl Day for Day17 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut wind: WindFactory = ipr.whole()?;
         let mut shapes = ShapeFactory::new();
         let mut board = Board::new();
-        let result = board.run(& mut wind, & mut shapes, 2022);
+        let result = board.run(& mut wind, & mut shapes, 2022, &ipr.visualizer);
         Ok(result.to_string())
     }
     
@@ -306,19 +333,24 @@ impl Day for Day17 {
         let mut wind: WindFactory = ipr.whole()?;
         let mut shapes = ShapeFactory::new();
         let mut board = Board::new();
-        board.run(& mut wind, & mut shapes, 10000);
+        board.run(& mut wind, & mut shapes, 10000, &ipr.visualizer);
 
         let (p_start, p_size) = board.find_pattern(shapes.s.len(), 4);
+        if p_size == 0 {
+            return Err(Box::new(AocError::solver("the rocks never settle into a repeating pattern")));
+        }
 
-        println!("p_start = {}, p_size = {}", p_start, p_size);
-
-        let target = 1000000000000;
-        let multiples = (target - p_start) / p_size;
-        let remainder = (target - p_start) % p_size;
-        let start_height = board.shape_list[p_start].top();
-        let pattern_height = board.shape_list[p_start + p_size].top() - board.shape_list[p_start].top();
-        let remainder_height = board.shape_list[p_start + p_size + remainder].top() - board.shape_list[p_start + p_size].top();
-        let result = start_height + (pattern_height*multiples) + (remainder_height);
+        // The tower grows by the same amount every p_size rocks from p_start on, so the height after target rocks is
+        // the height at the same point in the first repeat plus a rise for each repeat after it
+        let target: usize = ipr.param_or("rock_target", ROCK_TARGET)?;
+        let height_after = |rocks: usize| if rocks == 0 { 0 } else { board.heights[rocks - 1] };
+        let result = if target <= p_start + p_size {
+            height_after(target)
+        } else {
+            let first = p_start + (target - p_start) % p_size;
+            let pattern_height = height_after(p_start + p_size) - height_after(p_start);
+            height_after(first) + pattern_height * ((target - first) / p_size)
+        };
 
         Ok(result.to_string())
     }
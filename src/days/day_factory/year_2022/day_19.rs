@@ -1,4 +1,5 @@
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{DayInfo, Solution};
+use crate::error::AocError;
 use crate::input_reader;
 use regex::Regex;
 use std::cmp;
@@ -106,7 +107,7 @@ struct ProdState {
     store: Materials,
     time_remaining: i64,
 }
-struct Production {
+pub struct Production {
     blueprints: Vec<BluePrint>,
 }
 
@@ -121,7 +122,7 @@ fn find_time(requires: i64, store: i64, robots: i64) -> i64 {
 }
 
 impl Production {
-    pub fn run_blueprint(&self, bp: &BluePrint, steps: usize) -> i64 {
+    fn run_blueprint(&self, bp: &BluePrint, steps: usize) -> i64 {
         let mut best = 0;
         let mut no_build_best = 0;
 
@@ -153,6 +154,13 @@ impl Production {
                 no_build_best = no_build_best_val;
             }
 
+            // A geode bot finished in each of the t minutes left would add t-1, t-2, ... 0 geodes, t*(t-1)/2
+            // in all. If even that cannot beat a count already reached, nothing down this branch can.
+            let t = current.time_remaining;
+            if t <= 0 || no_build_best_val + t * (t - 1) / 2 <= no_build_best {
+                continue;
+            }
+
             // we can do -2 as there are never any bots built in last two time steps
             if current.time_remaining - 2 < 0 {
                 continue;
@@ -227,7 +235,6 @@ impl Production {
             }
         }
 
-        println!("Best = {}, nb_best = {}", best, no_build_best);
         cmp::max(best, no_build_best)
     }
 
@@ -242,7 +249,6 @@ impl Production {
     pub fn run2(&self, steps: usize) -> i64 {
         let mut value = 1;
         for (i, bp) in self.blueprints.to_vec().iter().enumerate() {
-            println!("i = {}, value = {}", i, value);
             value *= self.run_blueprint(bp, steps);
             if i == 2 {
                 break;
@@ -253,7 +259,7 @@ impl Production {
 }
 
 impl std::str::FromStr for Production {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let re = Regex::new(r"Blueprint (\d\d?): Each ore robot costs (\d\d?) ore. Each clay robot costs (\d\d?) ore. Each obsidian robot costs (\d\d?) ore and (\d\d?) clay. Each geode robot costs (\d\d?) ore and (\d\d?) obsidian.").unwrap();
@@ -262,7 +268,7 @@ impl std::str::FromStr for Production {
             blueprints: {
                 let mut b = Vec::new();
                 for l in s.lines().collect::<Vec<&str>>() {
-                    let caps = re.captures(l).unwrap();
+                    let caps = re.captures(l).ok_or_else(|| AocError::parse(l, "blueprint not recognised"))?;
                     b.push(BluePrint::new(
                         Robot::new(
                             Materials::new(caps[2].parse()?, 0, 0, 0),
@@ -289,15 +295,26 @@ impl std::str::FromStr for Production {
 }
 pub struct Day19 {}
 
-impl Day for Day19 {
-    fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let prod: Production = ipr.whole()?;
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 19,
+    title: "Not Enough Minerals",
+    create: || Box::new(Day19{}),
+};
+
+impl Solution for Day19 {
+    type Input = Production;
+
+    fn parse(&self, ipr: &input_reader::InputReader) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(ipr.whole()?)
+    }
+
+    fn part1(&self, prod: &Production) -> Result<String, Box<dyn Error>> {
         let result = prod.run(24);
         Ok(result.to_string())
     }
 
-    fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let prod: Production = ipr.whole()?;
+    fn part2(&self, prod: &Production) -> Result<String, Box<dyn Error>> {
         let result = prod.run2(32);
         Ok(result.to_string())
     }
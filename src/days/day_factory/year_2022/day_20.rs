@@ -1,4 +1,5 @@
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::{AocError, Context};
 use crate::input_reader;
 use std::error::Error;
 
@@ -66,9 +67,10 @@ impl LinkNodes {
                     }
                 }
                 _ => {
-                    // Note plus one so we can use move_node_right_of()
+                    // Note plus one so we can use move_node_right_of(). The one is added after the modulo as
+                    // otherwise a move of one less than a full lap wraps round to 0 and the node is put next to itself.
                     for _i in
-                        0..(self.nodes[move_to_index].val.abs() + 1) % (self.nodes.len() as i64 - 1)
+                        0..(self.nodes[move_to_index].val.abs() % (self.nodes.len() as i64 - 1)) + 1
                     {
                         move_to_index = self.nodes[move_to_index].left;
                     }
@@ -92,7 +94,6 @@ impl LinkNodes {
             for _m in 0..1000 {
                 current = self.nodes[current].right;
             }
-            println!("{} = {}", current, self.nodes[current].val);
             result += self.nodes[current].val;
         }
 
@@ -101,30 +102,38 @@ impl LinkNodes {
 }
 
 impl std::str::FromStr for LinkNodes {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ls: Vec<&str> = s.lines().collect();
-        let mut zero_index: usize = 0;
+        // Mixing moves each number modulo one less than the count
+        if ls.len() < 2 {
+            return Err(AocError::parse(s, "expected at least two numbers to mix"));
+        }
+        let zero_index = ls.iter().position(|l| *l == "0").ok_or_else(|| AocError::parse(s, "expected a 0 to count from"))?;
         Ok(Self {
             nodes: {
                 let mut n = Vec::new();
 
                 for l in &ls {
-                    if l == &"0" {
-                        zero_index = n.len();
-                    }
-                    n.push(LinkNode::new(l.parse()?, n.len(), ls.len()));
+                    n.push(LinkNode::new(l.parse().context(l)?, n.len(), ls.len()));
                 }
                 n
             },
-            zero_index: { zero_index },
+            zero_index,
         })
     }
 }
 
 pub struct Day20 {}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 20,
+    title: "Grove Positioning System",
+    create: || Box::new(Day20{}),
+};
+
 impl Day for Day20 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut nodes: LinkNodes = ipr.whole()?;
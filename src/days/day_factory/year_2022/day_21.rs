@@ -1,4 +1,5 @@
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::{AocError, Context};
 use crate::input_reader;
 use regex::Regex;
 use std::collections::HashMap;
@@ -21,16 +22,17 @@ impl Shout {
 }
 
 impl std::str::FromStr for Shout {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.chars().all(char::is_numeric) {
             Ok(Self::Val(s.parse()?))
         } else {
             let re = Regex::new(r"(....) ([+-\\*/]) (....)").unwrap();
-            let caps = re.captures(s).unwrap();
+            let caps = re.captures(s).ok_or_else(|| AocError::parse(s, "expected a number or an operation e.g. pppw + sjmn"))?;
             Ok(Self::Ins(
-                Instruction::new(caps[1].to_string(), caps[3].to_string(), &caps[2]).unwrap(),
+                Instruction::new(caps[1].to_string(), caps[3].to_string(), &caps[2])
+                    .ok_or_else(|| AocError::parse(s, format!("unknown operator '{}'", &caps[2])))?,
             ))
         }
     }
@@ -41,80 +43,92 @@ struct Shouts {
 }
 
 impl Shouts {
+    fn get(&self, monkey: &str) -> Result<&Shout, AocError> {
+        self.m.get(monkey).ok_or_else(|| AocError::solver(format!("there is no monkey {}", monkey)))
+    }
+
+    // The two monkeys whose numbers the monkey does a sum with
+    fn sides(&self, monkey: &str) -> Result<(String, String), AocError> {
+        let ins = self.get(monkey)?.get_instruction().ok_or_else(|| AocError::solver(format!("{} does not do a sum", monkey)))?;
+        let (lhs, rhs) = ins.get_monkeys();
+        Ok((lhs.to_string(), rhs.to_string()))
+    }
+
+    // None if the number depends on the unknown one. Every monkey a sum names is checked by from_str.
     pub fn solve(&self, monkey: &str) -> Option<i64> {
-        match self.m.get(monkey).unwrap() {
+        match self.m.get(monkey)? {
             Shout::Val(x) => Some(*x),
             Shout::Ins(x) => x.solve(self),
             Shout::Unknown => None,
         }
     }
 
-    pub fn solve_unknown(&self, monkey: &str, result: i64) -> i64 {
-        match self.m.get(monkey).unwrap() {
-            Shout::Val(x) => *x,
+    pub fn solve_unknown(&self, monkey: &str, result: i64) -> Result<i64, AocError> {
+        match self.get(monkey)? {
+            Shout::Val(x) => Ok(*x),
             Shout::Ins(x) => {
                 let (lhs, rhs) = x.get_monkeys();
                 let l_val = self.solve(lhs);
                 let r_val = self.solve(rhs);
-                match l_val {
-                    None => {
-                        let next_result = x.reverse_solve_lhs(result, r_val.unwrap());
+                match (l_val, r_val) {
+                    (None, Some(r_val)) => {
+                        let next_result = x.reverse_solve_lhs(result, r_val);
                         self.solve_unknown(lhs, next_result)
                     },
-                    Some(l_val) => {
+                    (Some(l_val), _) => {
                         let next_result = x.reverse_solve_rhs(result, l_val);
                         self.solve_unknown(rhs, next_result)
-                    }
+                    },
+                    (None, None) => Err(AocError::solver(format!("both sides of {} depend on the unknown number", monkey))),
                 }
             }
-            Shout::Unknown => result,
+            Shout::Unknown => Ok(result),
         }
     }
 
-    pub fn set_unknown(&mut self, monkey: &str) {
-        *self.m.get_mut(monkey).unwrap() = Shout::Unknown;
+    pub fn set_unknown(&mut self, monkey: &str) -> Result<(), AocError> {
+        self.get(monkey)?;
+        self.m.insert(monkey.to_string(), Shout::Unknown);
+        Ok(())
     }
 
-    pub fn set_equal(&mut self, monkey: &str) {
-        let (lhs, rhs) = self
-            .m
-            .get_mut(monkey)
-            .unwrap()
-            .get_instruction()
-            .unwrap()
-            .get_monkeys();
-        *self.m.get_mut(monkey).unwrap() =
-            Shout::Ins(Instruction::Equ(lhs.to_string(), rhs.to_string()));
+    pub fn set_equal(&mut self, monkey: &str) -> Result<(), AocError> {
+        let (lhs, rhs) = self.sides(monkey)?;
+        self.m.insert(monkey.to_string(), Shout::Ins(Instruction::Equ(lhs, rhs)));
+        Ok(())
     }
 
     pub fn set_val(&mut self, monkey: &str, val: i64) {
-        *self.m.get_mut(monkey).unwrap() = Shout::Val(val);
+        self.m.insert(monkey.to_string(), Shout::Val(val));
     }
 
-    pub fn set_sub(&mut self, monkey: &str) {
-        let (lhs, rhs) = self
-            .m
-            .get_mut(monkey)
-            .unwrap()
-            .get_instruction()
-            .unwrap()
-            .get_monkeys();
-        *self.m.get_mut(monkey).unwrap() =
-            Shout::Ins(Instruction::Sub(lhs.to_string(), rhs.to_string()));
+    pub fn set_sub(&mut self, monkey: &str) -> Result<(), AocError> {
+        let (lhs, rhs) = self.sides(monkey)?;
+        self.m.insert(monkey.to_string(), Shout::Ins(Instruction::Sub(lhs, rhs)));
+        Ok(())
     }
 }
 
 impl std::str::FromStr for Shouts {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s_lines: Vec<&str> = s.lines().collect();
         Ok(Self {
             m: {
                 let mut m = HashMap::new();
-                for l in s_lines {
-                    let l_split: Vec<&str> = l.split(':').collect();
-                    m.insert(l_split[0].trim().to_string(), l_split[1].trim().parse()?);
+                for l in &s_lines {
+                    let (name, shout) = l.split_once(':').ok_or_else(|| AocError::parse(l, "expected name: shout"))?;
+                    m.insert(name.trim().to_string(), shout.trim().parse().context(l)?);
+                }
+                for l in &s_lines {
+                    let shout = l.split_once(':').and_then(|(name, _)| m.get(name.trim()));
+                    if let Some(Shout::Ins(x)) = shout {
+                        let (lhs, rhs) = x.get_monkeys();
+                        if let Some(missing) = [lhs, rhs].into_iter().find(|n| !m.contains_key(*n)) {
+                            return Err(AocError::parse(l, format!("there is no monkey {}", missing)));
+                        }
+                    }
                 }
                 m
             },
@@ -167,14 +181,8 @@ impl Instruction {
     pub fn solve(&self, shouts: &Shouts) -> Option<i64> {
         let monkeys = self.get_monkeys();
 
-        let lhs = match shouts.solve(monkeys.0) {
-            Some(x) => x,
-            None => return None,
-        };
-        let rhs = match shouts.solve(monkeys.1) {
-            Some(x) => x,
-            None => return None,
-        };
+        let lhs = shouts.solve(monkeys.0)?;
+        let rhs = shouts.solve(monkeys.1)?;
 
         match self {
             Self::Add(_, _) => Some(lhs + rhs),
@@ -208,24 +216,33 @@ impl Instruction {
 
 pub struct Day21 {}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 21,
+    title: "Monkey Math",
+    create: || Box::new(Day21{}),
+};
+
 impl Day for Day21 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let shouts: Shouts = ipr.whole()?;
-        let val = shouts.solve("root").unwrap();
+        let val = shouts.solve("root").ok_or_else(|| AocError::solver("there is no monkey root"))?;
         Ok(val.to_string())
     }
 
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut shouts: Shouts = ipr.whole()?;
-        shouts.set_unknown("humn");
-        shouts.set_equal("root");
-        let val = shouts.solve_unknown("root", 0);
+        shouts.set_unknown("humn")?;
+        shouts.set_equal("root")?;
+        let val = shouts.solve_unknown("root", 0)?;
 
-        // Test answer
+        // Check the answer balances both sides of root
         shouts.set_val("humn", val);
-        shouts.set_sub("root");
-        let res = shouts.solve("root").unwrap();
-        println!("res = {}", res);
+        shouts.set_sub("root")?;
+        match shouts.solve("root") {
+            Some(0) => (),
+            res => return Err(Box::new(AocError::solver(format!("humn = {} leaves root unbalanced by {:?}", val, res)))),
+        }
 
         Ok(val.to_string())
     }
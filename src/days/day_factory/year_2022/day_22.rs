@@ -1,30 +1,13 @@
 use std::{error::Error, cmp::max};
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::DOUBLE_NEW_LINE;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
+use crate::days::day_factory::types::{split_groups, Point2};
+use crate::render::{Colour, FrameBuffer, Render, Visualizer};
 
 use regex::Regex;
 use std::collections::{HashMap, VecDeque};
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-pub struct SPoint {
-    pub x: i64,
-    pub y: i64,
-}
-
-impl SPoint {
-    pub fn new(x: i64, y: i64) -> Self {
-        SPoint{x,y}
-    }
-}
-
-impl std::ops::AddAssign for SPoint {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
 enum Instruction {
     Move(i64),
     Rotate(char),
@@ -35,7 +18,7 @@ struct Instructions {
 }
 
 impl std::str::FromStr for Instructions {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut i = Self{
@@ -76,7 +59,7 @@ impl CellType {
 }
 
 struct Map {
-    points: HashMap<SPoint, CellType>,
+    points: HashMap<Point2<i64>, CellType>,
     width: i64,
     height: i64,
 }
@@ -89,11 +72,11 @@ impl Map {
         if y + 1 > self.height {
             self.height = y + 1;
         }
-        self.points.insert(SPoint{x,y}, v);
+        self.points.insert(Point2::new(x, y), v);
     }
 
     pub fn get(&self, x: i64, y: i64) -> &CellType {
-        let t = self.points.get(&SPoint{x,y});
+        let t = self.points.get(&Point2::new(x, y));
         match t {
             Some(_) => t.unwrap(),
             None => &CellType::Void,
@@ -102,7 +85,7 @@ impl Map {
 }
 
 impl std::str::FromStr for Map {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut m = Map{
@@ -130,6 +113,16 @@ enum Direction {
     Down,
 }
 
+// The step taken going L, R, U or D
+fn delta(dir: char) -> Point2<i64> {
+    match dir {
+        'L' => Point2::left(),
+        'R' => Point2::right(),
+        'U' => Point2::up(),
+        _ => Point2::down(),
+    }
+}
+
 impl Direction {
     #[allow(dead_code)]
     pub fn as_char(&self) -> char {
@@ -193,35 +186,42 @@ impl Direction {
 //25
 //4
 
+// The way the faces of the map fold into a cube, the sample and actual inputs are different nets
+#[derive(Clone, Copy, PartialEq)]
+enum CubeLayout {
+    Sample,
+    Actual,
+}
+
+impl std::str::FromStr for CubeLayout {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sample" => Ok(Self::Sample),
+            "actual" => Ok(Self::Actual),
+            _ => Err(AocError::parse(s, "unknown cube_layout, expected sample or actual")),
+        }
+    }
+}
+
 struct VoidMap {
-    m: HashMap<(SPoint, char), (SPoint, i64)>,
+    m: HashMap<(Point2<i64>, char), (Point2<i64>, i64)>,
 }
 
 impl VoidMap {
-    pub fn add_map(& mut self, a: SPoint, a_dir: char, b:SPoint, b_dir: char, r:i64,) {
+    pub fn add_map(& mut self, a: Point2<i64>, a_dir: char, b:Point2<i64>, b_dir: char, r:i64,) {
         self.m.insert((a, a_dir), (b, r));
         self.m.insert((b, b_dir), (a, -r));
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub fn add_range(& mut self, ain: &SPoint, ad: char, a_dir: char, bin: &SPoint, bd: char, b_dir: char, c: i64, r: i64) {
+    pub fn add_range(& mut self, ain: &Point2<i64>, ad: char, a_dir: char, bin: &Point2<i64>, bd: char, b_dir: char, c: i64, r: i64) {
         let mut a = *ain;
         let mut b = *bin;
 
-        let adelta = match ad {
-            'L' => SPoint{x: -1, y: 0},
-            'R' => SPoint{x: 1, y: 0},
-            'U' => SPoint{x: 0, y: -1},
-            _ => SPoint{x: 0, y: 1}, // D
-        };
-
-        let bdelta = match bd {
-            'L' => SPoint{x: -1, y: 0},
-            'R' => SPoint{x: 1, y: 0},
-            'U' => SPoint{x: 0, y: -1},
-            _ => SPoint{x: 0, y: 1}, // D
-        };
-
+        let adelta = delta(ad);
+        let bdelta = delta(bd);
 
         for _i in 0..c {
             self.add_map(a, a_dir, b, b_dir, r);
@@ -230,41 +230,41 @@ impl VoidMap {
         }
     }
 
-    pub fn from_map(m: Map) -> Self {
+    pub fn from_map(m: &Map, layout: CubeLayout) -> Self {
         let mut vm = Self {
             m: HashMap::new(),
         };
         let face_size = max(m.width, m.height) / 4;
-        if face_size == 4 {
+        if layout == CubeLayout::Sample {
             // sample
             //side 0
-            vm.add_range(&SPoint::new((face_size*2)-1, 0), 'D', 'L', &SPoint::new(face_size, face_size-1), 'R', 'U', face_size, -1);
-            vm.add_range(&SPoint::new(face_size*2, -1), 'R', 'U', &SPoint::new(face_size-1, face_size-1), 'L', 'U', face_size, -2);
-            vm.add_range(&SPoint::new(face_size*3, 0), 'D', 'R', &SPoint::new(face_size*4, (face_size*3) - 1), 'U', 'R', face_size, -2);
+            vm.add_range(&Point2::new((face_size*2)-1, 0), 'D', 'L', &Point2::new(face_size, face_size-1), 'R', 'U', face_size, -1);
+            vm.add_range(&Point2::new(face_size*2, -1), 'R', 'U', &Point2::new(face_size-1, face_size-1), 'L', 'U', face_size, -2);
+            vm.add_range(&Point2::new(face_size*3, 0), 'D', 'R', &Point2::new(face_size*4, (face_size*3) - 1), 'U', 'R', face_size, -2);
             // side 1
-            vm.add_range(&SPoint::new(face_size*3, face_size), 'D', 'R', &SPoint::new((face_size*4) - 1, (face_size*2)-1), 'L', 'U', face_size, 1);
+            vm.add_range(&Point2::new(face_size*3, face_size), 'D', 'R', &Point2::new((face_size*4) - 1, (face_size*2)-1), 'L', 'U', face_size, 1);
             //side 2
-            vm.add_range(&SPoint::new((face_size*2) - 1, face_size*2), 'L', 'D', &SPoint::new((face_size*2) - 1, face_size*2), 'D', 'L', face_size, -1);
+            vm.add_range(&Point2::new((face_size*2) - 1, face_size*2), 'L', 'D', &Point2::new((face_size*2) - 1, face_size*2), 'D', 'L', face_size, -1);
             // side 3
-            vm.add_range(&SPoint::new(face_size*3, face_size*3), 'R', 'D', &SPoint::new(-1, (face_size*2) - 1), 'U', 'L', face_size, -1);
+            vm.add_range(&Point2::new(face_size*3, face_size*3), 'R', 'D', &Point2::new(-1, (face_size*2) - 1), 'U', 'L', face_size, -1);
             // side 4
-            vm.add_range(&SPoint::new(0, face_size*2), 'R', 'D', &SPoint::new((face_size*3) - 1, face_size*3), 'L', 'D', face_size, -2);
+            vm.add_range(&Point2::new(0, face_size*2), 'R', 'D', &Point2::new((face_size*3) - 1, face_size*3), 'L', 'D', face_size, -2);
         } else {
             //actual
             // side 0
-            vm.add_range(&SPoint::new(face_size-1, 0), 'D', 'L', &SPoint::new(-1, (face_size* 3) - 1), 'U', 'L', face_size, 2);
-            vm.add_range(&SPoint::new(face_size, -1), 'R', 'U', &SPoint::new(-1, face_size* 3), 'D', 'L', face_size, 1);
+            vm.add_range(&Point2::new(face_size-1, 0), 'D', 'L', &Point2::new(-1, (face_size* 3) - 1), 'U', 'L', face_size, 2);
+            vm.add_range(&Point2::new(face_size, -1), 'R', 'U', &Point2::new(-1, face_size* 3), 'D', 'L', face_size, 1);
             //  side 1
-            vm.add_range(&SPoint::new(face_size-1, face_size), 'D', 'L', &SPoint::new(0, (face_size* 2) - 1), 'R', 'U', face_size, -1);
-            vm.add_range(&SPoint::new(face_size*2, face_size), 'D', 'R', &SPoint::new(face_size*2, face_size), 'R', 'D', face_size, -1);
+            vm.add_range(&Point2::new(face_size-1, face_size), 'D', 'L', &Point2::new(0, (face_size* 2) - 1), 'R', 'U', face_size, -1);
+            vm.add_range(&Point2::new(face_size*2, face_size), 'D', 'R', &Point2::new(face_size*2, face_size), 'R', 'D', face_size, -1);
             // side 2
             
             // side 3
-            vm.add_range(&SPoint::new(face_size*2, -1), 'R', 'U', &SPoint::new(0, face_size*4), 'R', 'D', face_size, 0);
-            vm.add_range(&SPoint::new(face_size*3, 0), 'D', 'R', &SPoint::new(face_size*2, (face_size*3 )- 1), 'U', 'R', face_size, -2);
+            vm.add_range(&Point2::new(face_size*2, -1), 'R', 'U', &Point2::new(0, face_size*4), 'R', 'D', face_size, 0);
+            vm.add_range(&Point2::new(face_size*3, 0), 'D', 'R', &Point2::new(face_size*2, (face_size*3 )- 1), 'U', 'R', face_size, -2);
             
             // side 4?
-            vm.add_range(&SPoint::new(face_size, face_size*3), 'D', 'R', &SPoint::new(face_size, face_size*3), 'R', 'D', face_size, -1);
+            vm.add_range(&Point2::new(face_size, face_size*3), 'D', 'R', &Point2::new(face_size, face_size*3), 'R', 'D', face_size, -1);
         }
 
 
@@ -275,17 +275,17 @@ impl VoidMap {
 struct Passcode {
     map: Map,
     instructions: Instructions,
-    position: SPoint,
+    position: Point2<i64>,
     direction: Direction,
     void_map: VoidMap,
-    path: HashMap<SPoint, Direction>,
+    path: HashMap<Point2<i64>, Direction>,
 }
 
 impl Passcode {
     fn set_start_point(& mut self) {
         for x in 0..self.map.width {
             if self.map.get(x, 0) == &CellType::Space {
-                self.position = SPoint{x, y: 0};
+                self.position = Point2::new(x, 0);
                 return;
             }
         }   
@@ -320,12 +320,7 @@ impl Passcode {
                             }
                         }
 
-                        jump_point += match new_r.as_char() {
-                            'L' => SPoint{x: -1, y: 0},
-                            'R' => SPoint{x: 1, y: 0},
-                            'U' => SPoint{x: 0, y: -1},
-                            _ => SPoint{x: 0, y: 1}, // D
-                        };
+                        jump_point += delta(new_r.as_char());
 
                         if *self.map.get(jump_point.x,  jump_point.y) == CellType::Wall {
                             break;
@@ -393,12 +388,7 @@ impl Passcode {
                             }
                         }
 
-                        jump_point += match new_r.as_char() {
-                            'L' => SPoint{x: -1, y: 0},
-                            'R' => SPoint{x: 1, y: 0},
-                            'U' => SPoint{x: 0, y: -1},
-                            _ => SPoint{x: 0, y: 1}, // D
-                        };
+                        jump_point += delta(new_r.as_char());
 
                         if *self.map.get(jump_point.x,  jump_point.y) == CellType::Wall {
                             break;
@@ -457,54 +447,58 @@ impl Passcode {
         }
     }
 
-    pub fn apply_instructions(& mut self, use_void_map: bool) {
+    pub fn apply_instructions(& mut self, use_void_map: bool, vis: &Visualizer) {
+        vis.show(self);
         for i in 0..self.instructions.i.len() {
             self.apply_instruction(i, use_void_map);
+            vis.show(self);
         }
     }
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        for y in -1..self.map.height+1 {
-            print!("{}", y);
-            for x in -1..self.map.width+1 {
-                if self.path.contains_key(&SPoint{x,y}) {
-                    let d = self.path.get(&SPoint{x,y}).unwrap();
-                    match d {
-                        Direction::Left => print!("<"),
-                        Direction::Right => print!(">"),
-                        Direction::Up => print!("^"),
-                        Direction::Down => print!("V"),
-                    };
-                    
-                } else {
-                    match self.map.get(x, y) {
-                        CellType::Space => print!("."),
-                        CellType::Wall => print!("#"),
-                        CellType::Void => print!("_"),
-                    }
-                }
-            }
-            println!();
-        }
-        println!();
-    }
 
     pub fn get_code(&self) -> i64{
         (1000 * (self.position.y + 1)) + (4 * (self.position.x + 1)) + self.direction.score()
     }
 }
 
+impl Render for Passcode {
+    // The board with the path taken so far and where we are now
+    fn render(&self) -> FrameBuffer {
+        let mut fb = FrameBuffer::new(self.map.width as usize, self.map.height as usize);
+        for (p, c) in &self.map.points {
+            match c {
+                CellType::Space => fb.set(p.x as usize, p.y as usize, '.', Colour::Default),
+                CellType::Wall => fb.set(p.x as usize, p.y as usize, '#', Colour::Grey),
+                CellType::Void => (),
+            }
+        }
+        for (p, d) in &self.path {
+            let c = match d {
+                Direction::Left => '<',
+                Direction::Right => '>',
+                Direction::Up => '^',
+                Direction::Down => 'v',
+            };
+            fb.set(p.x as usize, p.y as usize, c, Colour::Yellow);
+        }
+        fb.set(self.position.x as usize, self.position.y as usize, '@', Colour::Red);
+        fb
+    }
+}
+
 impl std::str::FromStr for Passcode {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let split: Vec<&str> = s.split(&DOUBLE_NEW_LINE).collect();
+        let split = split_groups(s);
+        if split.len() != 2 {
+            return Err(AocError::parse(s, "expected the map and the path separated by a blank line"));
+        }
         let mut pc = Self{
             map: split[0].parse()?,
             instructions: split[1].parse()?,
-            position: SPoint { x: 0, y: 0 },
+            position: Point2::new(0, 0),
             direction: Direction::Right,
-            void_map: VoidMap::from_map(split[0].parse()?),
+            void_map: VoidMap { m: HashMap::new() },
             path: HashMap::new(),
         };
         pc.set_start_point();
@@ -514,17 +508,25 @@ impl std::str::FromStr for Passcode {
 
 pub struct Day22{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 22,
+    title: "Monkey Map",
+    create: || Box::new(Day22{}),
+};
+
 impl Day for Day22 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut game:Passcode = ipr.whole()?;
-        game.apply_instructions(false);
+        game.apply_instructions(false, &ipr.visualizer);
         let code = game.get_code();
         Ok(code.to_string())
     }
     
     fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut game:Passcode = ipr.whole()?;
-        game.apply_instructions(true);
+        game.void_map = VoidMap::from_map(&game.map, ipr.param_or("cube_layout", CubeLayout::Actual)?);
+        game.apply_instructions(true, &ipr.visualizer);
         let code = game.get_code();
         Ok(code.to_string())
     }
@@ -1,60 +1,10 @@
 use std::collections::{HashSet, HashMap};
 use std::error::Error;
-use std::ops::Range;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-pub struct SPoint {
-    pub x: i64,
-    pub y: i64,
-}
-
-impl SPoint {
-    fn get_range(&self, x_range: Range<i64>, y_range: Range<i64>) -> Vec<Self> {
-        let mut n:Vec<Self> = Vec::new();
-        for x in x_range {
-            for y in y_range.clone() {
-                if x == self.x && y == self.y {
-                    continue;
-                }
-                n.push(Self{x,y});
-            }
-        }
-        n
-    }
-
-    pub fn get_all_neighbours(&self) -> Vec<Self> {
-        let x_range = self.x - 1 .. self.x + 2;
-        let y_range = self.y - 1 .. self.y + 2;
-        self.get_range(x_range, y_range)
-
-    }
-    
-    pub fn get_north(&self) -> Vec<Self> {
-        let x_range = self.x - 1 .. self.x + 2;
-        let y_range = self.y - 1 .. self.y;
-        self.get_range(x_range, y_range)
-    }
-
-    pub fn get_south(&self) -> Vec<Self> {
-        let x_range = self.x - 1 .. self.x + 2;
-        let y_range = self.y + 1 .. self.y + 2;
-        self.get_range(x_range, y_range)
-    }
-
-    pub fn get_east(&self) -> Vec<Self> {
-        let x_range = self.x + 1 .. self.x + 2;
-        let y_range = self.y - 1 .. self.y + 2;
-        self.get_range(x_range, y_range)
-    }
-
-    pub fn get_west(&self) -> Vec<Self> {
-        let x_range = self.x - 1 .. self.x;
-        let y_range = self.y - 1 .. self.y + 2;
-        self.get_range(x_range, y_range)
-    }
-}
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
+use crate::days::day_factory::types::Point2;
+use crate::render::{Colour, FrameBuffer, Render};
 
 struct Phase {
     current: i64,
@@ -72,46 +22,42 @@ impl Phase {
         }
     }
 
-    pub fn get_check_points_inc(&self, p: &SPoint, phase_inc: i64) -> Vec<SPoint> {
-        let check = (self.current + phase_inc) % 4;
-        match check {
-            0 => p.get_north(),
-            1 => p.get_south(),
-            2 => p.get_west(),
-            _ => p.get_east()
-        }
+    // The three points an elf looks at before moving a step in the direction, straight ahead and to either side
+    pub fn get_check_points_inc(&self, p: &Point2<i64>, phase_inc: i64) -> Vec<Point2<i64>> {
+        let d = self.get_delta(phase_inc);
+        vec![*p + d, *p + d + d.rotate_left(), *p + d + d.rotate_right()]
     }
 
-    pub fn get_delta(&self, phase_inc: i64) -> SPoint {
+    pub fn get_delta(&self, phase_inc: i64) -> Point2<i64> {
         let check = (self.current + phase_inc) % 4;
         match check {
-            0 => SPoint{x: 0, y: -1},
-            1 => SPoint{x: 0, y: 1},
-            2 => SPoint{x: -1, y: 0},
-            _ => SPoint{x: 1, y: 0}
+            0 => Point2::up(),
+            1 => Point2::down(),
+            2 => Point2::left(),
+            _ => Point2::right()
         }
     }
 }
 
 struct Planting {
-    elves: HashSet<SPoint>,
-    min: SPoint,
-    max: SPoint,
+    elves: HashSet<Point2<i64>>,
+    min: Point2<i64>,
+    max: Point2<i64>,
     phase: Phase,
 }
 
 impl Planting {
     pub fn add_elf(& mut self, x: i64, y: i64) {
-        self.elves.insert(SPoint{x, y});
+        self.elves.insert(Point2::new(x, y));
     }
 
     pub fn take_turn(& mut self) -> bool {
         let mut elf_moved = false;
-        let mut proposed: HashMap<SPoint, (i64, SPoint)> = HashMap::new();
+        let mut proposed: HashMap<Point2<i64>, (i64, Point2<i64>)> = HashMap::new();
         for e in &self.elves{
             // Check for any neighbours
             let mut has_neighbour = false;
-            for p in e.get_all_neighbours() {
+            for p in e.neighbours8() {
                 if self.elves.contains(&p) {
                     has_neighbour = true;
                     break;
@@ -133,7 +79,7 @@ impl Planting {
                 
                 if !has_neighbour {
                     let delta = self.phase.get_delta(test);
-                    proposed.entry(SPoint{x: e.x + delta.x, y: e.y + delta.y}).and_modify(|c| c.0 += 1).or_insert((1, *e));
+                    proposed.entry(*e + delta).and_modify(|c| c.0 += 1).or_insert((1, *e));
                     break;
                 }
             }
@@ -150,47 +96,22 @@ impl Planting {
         elf_moved
     }
 
-    fn update_min_max(& mut self) {
-        self.min.x = i64::MAX;
-        self.min.y = i64::MAX;
-        self.max.x = i64::MIN;
-        self.max.y = i64::MIN;
+    // The smallest rectangle holding every elf
+    fn bounds(&self) -> (Point2<i64>, Point2<i64>) {
+        let mut min = Point2::new(i64::MAX, i64::MAX);
+        let mut max = Point2::new(i64::MIN, i64::MIN);
 
         for e in &self.elves {
-            let x = e.x;
-            let y = e.y;
-            if self.max.x < x {
-                self.max.x = x;
-            }
-            if self.min.x > x {
-                self.min.x = x;
-            }
-            if self.max.y < y {
-                self.max.y = y;
-            }
-            if self.min.y > y {
-                self.min.y = y;
-            }
+            min.x = min.x.min(e.x);
+            min.y = min.y.min(e.y);
+            max.x = max.x.max(e.x);
+            max.y = max.y.max(e.y);
         }
-
-
+        (min, max)
     }
 
-    #[allow(dead_code)]
-    pub fn print(& mut self) {
-        self.update_min_max();
-        for y in self.min.y..self.max.y + 1 {
-            print!("{}", y);
-            for x in self.min.x..self.max.x + 1 {
-                if self.elves.contains(&SPoint{x,y}) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
-        }
-        println!();
+    fn update_min_max(& mut self) {
+        (self.min, self.max) = self.bounds();
     }
 
     pub fn get_score(& mut self) -> i64{
@@ -199,15 +120,29 @@ impl Planting {
     }
 }
 
+impl Render for Planting {
+    fn render(&self) -> FrameBuffer {
+        if self.elves.is_empty() {
+            return FrameBuffer::new(0, 0);
+        }
+        let (min, max) = self.bounds();
+        let mut fb = FrameBuffer::filled((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, '.');
+        for e in &self.elves {
+            fb.set((e.x - min.x) as usize, (e.y - min.y) as usize, '#', Colour::Green);
+        }
+        fb
+    }
+}
+
 impl std::str::FromStr for Planting {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let sl:Vec<&str> = s.lines().collect();
         let mut p = Self {
             elves: HashSet::new(),
-            min: SPoint{x: i64::MAX, y: i64::MAX},
-            max: SPoint{x: i64::MIN, y: i64::MIN},
+            min: Point2::new(i64::MAX, i64::MAX),
+            max: Point2::new(i64::MIN, i64::MIN),
             phase: Phase::new(),
         };
         for (y, line) in sl.iter().enumerate() {
@@ -224,12 +159,21 @@ impl std::str::FromStr for Planting {
 
 pub struct Day23{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 23,
+    title: "Unstable Diffusion",
+    create: || Box::new(Day23{}),
+};
+
 impl Day for Day23 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
         let mut planting: Planting = ipr.whole()?;
+        ipr.visualizer.show(&planting);
         for _i in 0..10 {
             planting.take_turn();
             planting.phase.next_phase();
+            ipr.visualizer.show(&planting);
         }
 
         Ok(planting.get_score().to_string())
@@ -239,10 +183,12 @@ impl Day for Day23 {
         let mut planting: Planting = ipr.whole()?;
         let mut count:i64 = 0;
         let mut run = true;
+        ipr.visualizer.show(&planting);
         while run {
             run = planting.take_turn();
             planting.phase.next_phase();
             count += 1;
+            ipr.visualizer.show(&planting);
         }
 
         Ok(count.to_string())
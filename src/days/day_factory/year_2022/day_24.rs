@@ -1,17 +1,17 @@
-use std::collections::{HashMap, VecDeque, HashSet};
+use std::collections::HashMap;
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
-use crate::days::day_factory::types::Point;
+use crate::days::day_factory::{DayInfo, Solution};
+use crate::error::AocError;
+use crate::days::day_factory::search::bfs;
+use crate::days::day_factory::types::{Bounds, Point};
+use crate::render::{Colour, FrameBuffer, Render, Visualizer};
 use num::integer::lcm;
 #[derive(Clone, Copy)]
 struct Blizard {
     d: char,
     p: Point,
-    max_x: usize,
-    max_y: usize,
-    min_x: usize,
-    min_y: usize,
+    valley: Bounds,
 }
 
 
@@ -25,17 +25,19 @@ impl Blizard {
             _ => new_point.y += 1,
         }
 
-        if new_point.x > self.max_x {
-            new_point.x = self.min_x;
+        // Blizards that leave the valley come back in on the far side
+        let (min, max) = (self.valley.min(), self.valley.max());
+        if new_point.x > max.x {
+            new_point.x = min.x;
         }
-        if new_point.x < self.min_x {
-            new_point.x = self.max_x;
+        if new_point.x < min.x {
+            new_point.x = max.x;
         }
-        if new_point.y > self.max_y {
-            new_point.y = self.min_y;
+        if new_point.y > max.y {
+            new_point.y = min.y;
         }
-        if new_point.y < self.min_y {
-            new_point.y = self.max_y;
+        if new_point.y < min.y {
+            new_point.y = max.y;
         }
 
         new_point
@@ -45,10 +47,8 @@ impl Blizard {
 struct WindMap {
     blizards: Vec<Blizard>,
     points: HashMap<Point, Blizard>,
-    max_x: usize,
-    max_y: usize,
-    min_x: usize,
-    min_y: usize,
+    // The open ground inside the walls
+    valley: Bounds,
 }
 
 impl WindMap {
@@ -56,10 +56,7 @@ impl WindMap {
         let mut new_map = Self {
             blizards: Vec::new(),
             points: HashMap::new(),
-            max_x: self.max_x,
-            max_y: self.max_y,
-            min_x: self.min_x,
-            min_y: self.min_y,
+            valley: self.valley,
         };
 
         for b in &self.blizards {
@@ -70,44 +67,75 @@ impl WindMap {
     }
 
     pub fn add_blizard(&mut self, p: Point, d: char) {
-        self.blizards.push(Blizard { d, p, max_x: self.max_x, max_y: self.max_y, min_x: self.min_x, min_y: self.min_y });
-        self.points.insert(p, Blizard { d, p, max_x: self.max_x, max_y: self.max_y, min_x: self.min_x, min_y: self.min_y });
+        self.blizards.push(Blizard { d, p, valley: self.valley });
+        self.points.insert(p, Blizard { d, p, valley: self.valley });
     }
 
     pub fn is_safe(&self, p: &Point) -> bool {
         !self.points.contains_key(p)
     }
+}
 
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        for y in 0..self.max_y + 2 {
-            for x in 0..self.max_x + 2 {
-                if self.points.contains_key(&Point{x,y}) {
-                    print!("{}", self.points.get(&Point{x,y}).unwrap().d);
-                } else {
-                    print!(".");
-                }
+impl Render for WindMap {
+    // The valley with its walls, a cell with more than one blizard shows how many there are
+    fn render(&self) -> FrameBuffer {
+        let (width, height) = (self.valley.max().x + 2, self.valley.max().y + 2);
+        let mut fb = FrameBuffer::filled(width, height, '.');
+        for x in 0..width {
+            fb.set(x, 0, '#', Colour::Grey);
+            fb.set(x, height - 1, '#', Colour::Grey);
+        }
+        for y in 0..height {
+            fb.set(0, y, '#', Colour::Grey);
+            fb.set(width - 1, y, '#', Colour::Grey);
+        }
+        fb.set(self.valley.min().x, 0, '.', Colour::Default);
+        fb.set(self.valley.max().x, height - 1, '.', Colour::Default);
+
+        let mut counts: HashMap<Point, usize> = HashMap::new();
+        for b in &self.blizards {
+            *counts.entry(b.p).or_insert(0) += 1;
+        }
+        for (p, b) in &self.points {
+            match counts[p] {
+                1 => fb.set(p.x, p.y, b.d, Colour::Cyan),
+                n => fb.set(p.x, p.y, char::from_digit(n as u32, 10).unwrap_or('*'), Colour::Blue),
             }
-            println!();
         }
-        println!();
+        fb
+    }
+}
+
+// Everywhere the expedition could be at a time, drawn over the blizards
+struct Valley<'a> {
+    wind_map: &'a WindMap,
+    expedition: &'a [Point],
+}
+
+impl Render for Valley<'_> {
+    fn render(&self) -> FrameBuffer {
+        let mut fb = self.wind_map.render();
+        for p in self.expedition {
+            fb.set(p.x, p.y, 'E', Colour::Yellow);
+        }
+        fb
     }
 }
 
 impl std::str::FromStr for WindMap {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
 
         let sl: Vec<&str> = s.lines().collect();
+        let width = sl.first().map_or(0, |l| l.len());
+        let valley = Bounds::new(Point{x: 1, y: 1}, Point{x: width.saturating_sub(2), y: sl.len().saturating_sub(2)})
+            .ok_or_else(|| AocError::parse(s, "the valley must be at least 3x3 including its walls"))?;
 
         let mut wind_map = Self{
             blizards: Vec::new(),
             points: HashMap::new(),
-            max_x: sl[0].len()-2,
-            max_y: sl.len()-2,
-            min_x: 1,
-            min_y: 1,
+            valley,
         };
 
         for (y, l) in sl.iter().enumerate() {
@@ -122,29 +150,25 @@ impl std::str::FromStr for WindMap {
     }
 }
 
-struct Crossing {
+pub struct Crossing {
     wind_maps: Vec<WindMap>,
-    max_x: usize,
-    max_y: usize,
-    min_x: usize,
-    min_y: usize,
+    valley: Bounds,
     start: Point,
     end: Point,
+    vis: Visualizer,
 }
 
 impl Crossing {
-    pub fn from_wind_map(wind_map: WindMap) -> Self {
+    fn from_wind_map(wind_map: WindMap, vis: Visualizer) -> Self {
         let mut crossing = Crossing{
             wind_maps: Vec::new(),
-            max_x: wind_map.max_x,
-            max_y: wind_map.max_y,
-            min_x: wind_map.min_x,
-            min_y: wind_map.min_y,
+            valley: wind_map.valley,
             start: Point{x: 1, y: 0},
-            end: Point{x: wind_map.max_x, y: wind_map.max_y + 1}
+            end: Point{x: wind_map.valley.max().x, y: wind_map.valley.max().y + 1},
+            vis,
         };
 
-        let map_count = lcm((wind_map.max_x-wind_map.min_x) + 1, (wind_map.max_y - wind_map.min_y) + 1);
+        let map_count = lcm(wind_map.valley.width(), wind_map.valley.height());
         crossing.wind_maps.push(wind_map);
         for _i in 1..map_count {
             crossing.wind_maps.push(crossing.wind_maps[crossing.wind_maps.len()-1].get_next());
@@ -153,7 +177,8 @@ impl Crossing {
         crossing
     }
 
-    pub fn run(&self, reverse: bool, wind_offset: usize) -> usize {
+    // The minutes to cross starting wind_offset minutes in, None if the blizards always block the way
+    pub fn run(&self, reverse: bool, wind_offset: usize) -> Option<usize> {
 
         let (start, end) = if reverse {
             (self.end, Point{x: self.start.x, y: self.start.y+1})
@@ -161,36 +186,43 @@ impl Crossing {
             (self.start, Point{x: self.end.x, y: self.end.y-1})
         };
 
-        let state = (start.as_point(), wind_offset);
-        let mut state_queue: VecDeque<(Point, usize)> = VecDeque::new();
-        state_queue.push_back(state);
-
-        let mut seen: HashSet<(Point, usize)> = HashSet::new();
-        seen.insert(state);
-
-        let mut best = usize::MAX;
-
-        while !state_queue.is_empty() {
-            let (pos, time) = state_queue.pop_front().unwrap();
-
-            if pos == end && time < best {
-                best = time - wind_offset;
-                break;
-            }
-            
-            let mut neighbours = pos.get_adjacent_neighbours_min(self.max_x, self.max_y, self.min_x, self.min_y);
-
-            neighbours.push(pos);
-            for n in neighbours {
-                let next_time = (time + 1) % self.wind_maps.len();
-                if self.wind_maps[next_time].is_safe(&n) && !seen.contains(&(n.as_point(), time + 1)){
-                    state_queue.push_back((n.as_point(), time + 1));
-                    seen.insert((n, time + 1));
+        // The blizards repeat, so a state only needs the time into their cycle
+        let cycle = self.wind_maps.len();
+        let mut shown = (wind_offset % cycle, Vec::new());
+
+        let path = bfs(
+            (start, wind_offset % cycle),
+            |&(pos, phase)| {
+                // The search goes one minute at a time, so once it moves on the last minute is complete
+                if self.vis.enabled() {
+                    if shown.0 != phase {
+                        self.show(shown.0, &shown.1);
+                        shown = (phase, Vec::new());
+                    }
+                    shown.1.push(pos);
                 }
-            }
 
+                // The start and end are just outside the valley, so from there the only way is in
+                let next = (phase + 1) % cycle;
+                let mut neighbours = pos.get_adjacent_neighbours(&self.valley);
+                neighbours.push(pos);
+                neighbours.into_iter()
+                    .filter(|n| self.wind_maps[next].is_safe(n))
+                    .map(|n| (n, next))
+                    .collect::<Vec<_>>()
+            },
+            |&(pos, _)| pos == end,
+        );
+        if self.vis.enabled() {
+            self.show(shown.0, &shown.1);
         }
-        best + 1
+
+        // One more minute to step out of the valley
+        path.map(|p| p.cost + 1)
+    }
+
+    fn show(&self, phase: usize, expedition: &[Point]) {
+        self.vis.show(&Valley { wind_map: &self.wind_maps[phase], expedition });
     }
 }
 
@@ -198,20 +230,31 @@ impl Crossing {
 
 pub struct Day24{}
 
-impl Day for Day24 {
-    fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 24,
+    title: "Blizzard Basin",
+    create: || Box::new(Day24{}),
+};
+
+impl Solution for Day24 {
+    type Input = Crossing;
+
+    fn parse(&self, ipr: &input_reader::InputReader) -> Result<Self::Input, Box<dyn Error>> {
         let wind_map: WindMap = ipr.whole()?;
-        let crossing = Crossing::from_wind_map(wind_map);
-        Ok(crossing.run(false, 0).to_string())
+        Ok(Crossing::from_wind_map(wind_map, ipr.visualizer.clone()))
+    }
+
+    fn part1(&self, crossing: &Crossing) -> Result<String, Box<dyn Error>> {
+        let time = crossing.run(false, 0).ok_or("there is no way across")?;
+        Ok(time.to_string())
     }
     
-    fn run2(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let wind_map: WindMap = ipr.whole()?;
-        let crossing = Crossing::from_wind_map(wind_map);
+    fn part2(&self, crossing: &Crossing) -> Result<String, Box<dyn Error>> {
         let mut total = 0;
-        total += crossing.run(false, total);
-        total += crossing.run(true, total);
-        total += crossing.run(false, total);
+        for reverse in [false, true, false] {
+            total += crossing.run(reverse, total).ok_or("there is no way across")?;
+        }
         Ok(total.to_string())
     }
 }
\ No newline at end of file
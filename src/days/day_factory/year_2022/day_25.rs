@@ -1,18 +1,22 @@
 use std::error::Error;
 use crate::input_reader;
-use crate::days::day_factory::Day;
+use crate::days::day_factory::{Day, DayInfo};
+use crate::error::AocError;
 
-fn from_snafu(s: &str) -> i64{
+fn from_snafu(s: &str) -> Result<i64, AocError> {
+    if s.is_empty() {
+        return Err(AocError::parse(s, "expected a snafu number e.g. 1=-0-2"));
+    }
     let mut coeficent = 1;
     let mut total = 0;
 
-    for c in (0..s.len()).rev() {
-        let v = lookup_from_snafu(s.chars().nth(c).unwrap());
+    for c in s.chars().rev() {
+        let v = lookup_from_snafu(c)?;
         total += v * coeficent;
         coeficent *= 5;
     }
     
-    total
+    Ok(total)
 }
 
 fn to_snafu(val_in: i64) -> String {
@@ -33,13 +37,14 @@ fn to_snafu(val_in: i64) -> String {
     total
 }
 
-fn lookup_from_snafu(c: char) -> i64 {
+fn lookup_from_snafu(c: char) -> Result<i64, AocError> {
     match c {
-        '2' => 2,
-        '1' => 1,
-        '0' => 0,
-        '-' => -1,
-        _ => -2 // '='
+        '2' => Ok(2),
+        '1' => Ok(1),
+        '0' => Ok(0),
+        '-' => Ok(-1),
+        '=' => Ok(-2),
+        _ => Err(AocError::parse("", format!("'{}' is not a snafu digit, expected 2, 1, 0, - or =", c))),
     }
 }
 
@@ -53,15 +58,32 @@ fn lookup_to_snafu(i: i64) -> String {
     })
 }
 
+struct Snafu(i64);
+
+impl std::str::FromStr for Snafu {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(from_snafu(s)?))
+    }
+}
+
 pub struct Day25{}
 
+pub const INFO: DayInfo = DayInfo {
+    year: 2022,
+    day: 25,
+    title: "Full of Hot Air",
+    create: || Box::new(Day25{}),
+};
+
 impl Day for Day25 {
     fn run1(&self, ipr: input_reader::InputReader) -> Result<String, Box<dyn Error>> {
-        let data:Vec<String> = ipr.vec_1d_newln()?;
+        let data:Vec<Snafu> = ipr.vec_1d_newln()?;
 
         let mut total = 0;
         for snafu in &data {
-            total += from_snafu(snafu);
+            total += snafu.0;
         }
 
         Ok(to_snafu(total))
@@ -0,0 +1,151 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum AocError {
+    // Input that could not be understood. FromStr impls only know the text they were given,
+    // the InputReader fills in the file and line once the error reaches it.
+    Parse {
+        file: String,
+        line: Option<usize>,
+        text: String,
+        reason: String,
+    },
+    MissingFile {
+        file: String,
+        reason: String,
+    },
+    UnknownYear {
+        year: String,
+        available: Vec<String>,
+    },
+    UnknownDay {
+        year: String,
+        day: String,
+        available: Vec<String>,
+    },
+    UnknownStage(String),
+    Solver(String),
+}
+
+impl AocError {
+    pub fn parse(text: &str, reason: impl fmt::Display) -> Self {
+        Self::Parse {
+            file: String::new(),
+            line: None,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn solver(reason: impl fmt::Display) -> Self {
+        Self::Solver(reason.to_string())
+    }
+
+    // Sets the offending text of a parse error that was raised without any e.g. from a ParseIntError
+    pub fn with_text(self, text: &str) -> Self {
+        match self {
+            Self::Parse { file, line, text: t, reason } if t.is_empty() => Self::Parse {
+                file,
+                line,
+                text: text.to_string(),
+                reason,
+            },
+            e => e,
+        }
+    }
+
+    // Fills in where a parse error came from. chunk is the part of content that was being parsed and
+    // starts at byte offset chunk_start, it is used as the text if the error has none. The line is found
+    // by looking for the text, first from the start of the chunk and then from the start of the file.
+    pub fn locate(self, file: &str, content: &str, chunk_start: usize, chunk: &str) -> Self {
+        match self.with_text(chunk) {
+            Self::Parse { file: f, line, text, reason } => {
+                let line = line.or_else(|| {
+                    // An empty line has nothing to look for, but it is where the chunk starts
+                    if text.is_empty() {
+                        return content.get(..chunk_start).map(|c| c.matches('\n').count() + 1);
+                    }
+                    let start = content.get(chunk_start..)?.find(&text).map(|i| i + chunk_start);
+                    start.or_else(|| content.find(&text)).map(|i| content[..i].matches('\n').count() + 1)
+                });
+                Self::Parse {
+                    file: if f.is_empty() { file.to_string() } else { f },
+                    line,
+                    text,
+                    reason,
+                }
+            },
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse { file, line, text, reason } => {
+                match (file.is_empty(), line) {
+                    (false, Some(l)) => write!(f, "{}:{}: {}", file, l, reason)?,
+                    (false, None) => write!(f, "{}: {}", file, reason)?,
+                    (true, _) => write!(f, "parse error: {}", reason)?,
+                }
+                // Only the first line of a multi-line section is worth showing
+                let mut lines = text.lines();
+                if let Some(first) = lines.next() {
+                    write!(f, "\n    {}", first)?;
+                    if lines.next().is_some() {
+                        write!(f, " ...")?;
+                    }
+                }
+                Ok(())
+            },
+            Self::MissingFile { file, reason } => write!(f, "could not read input file {}: {}", file, reason),
+            Self::UnknownYear { year, available } => {
+                write!(f, "unknown year '{}', available years are {}", year, available.join(", "))
+            },
+            Self::UnknownDay { year, day, available } => write!(
+                f,
+                "unknown day '{}' for {}, available days are {}",
+                day,
+                year,
+                available.join(", ")
+            ),
+            Self::UnknownStage(stage) => write!(f, "unknown stage '{}', expected 1 or 2", stage),
+            Self::Solver(reason) => write!(f, "solver failed: {}", reason),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> Self {
+        Self::parse("", e)
+    }
+}
+
+impl From<serde_json::Error> for AocError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::parse("", e)
+    }
+}
+
+impl From<Infallible> for AocError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+// Attaches the text being parsed to a failed parse e.g. l.parse::<Point>().context(l)?
+pub trait Context<T> {
+    fn context(self, text: &str) -> Result<T, AocError>;
+}
+
+impl<T, E: Into<AocError>> Context<T> for Result<T, E> {
+    fn context(self, text: &str) -> Result<T, AocError> {
+        self.map_err(|e| e.into().with_text(text))
+    }
+}
@@ -0,0 +1,190 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use crate::error::AocError;
+use crate::render::{Colour, FrameBuffer};
+
+// The colours frames are drawn with as images, one per render::Colour plus the background
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    pub background: [u8; 3],
+    pub colours: [[u8; 3]; 9],
+}
+
+impl Palette {
+    pub const NAMES: &[&str] = &["dark", "light", "mono"];
+
+    // Maps a palette index as used in the image data to its colour, 0 is the background
+    fn rgb(&self, index: u8) -> [u8; 3] {
+        match index {
+            0 => self.background,
+            i => self.colours[i as usize - 1],
+        }
+    }
+
+    fn flatten(&self) -> Vec<u8> {
+        (0..=self.colours.len() as u8).flat_map(|i| self.rgb(i)).collect()
+    }
+
+    pub fn set(&mut self, colour: Colour, rgb: [u8; 3]) {
+        self.colours[colour.index()] = rgb;
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        // The dark blue of the puzzle pages with bright colours on top
+        Self {
+            background: [0x0f, 0x0f, 0x23],
+            colours: [
+                [0xcc, 0xcc, 0xcc],
+                [0xff, 0x44, 0x44],
+                [0x00, 0xcc, 0x00],
+                [0xff, 0xff, 0x66],
+                [0x44, 0x88, 0xff],
+                [0xcc, 0x44, 0xcc],
+                [0x44, 0xcc, 0xcc],
+                [0xff, 0xff, 0xff],
+                [0x66, 0x66, 0x77],
+            ],
+        }
+    }
+}
+
+impl std::str::FromStr for Palette {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "dark" => Ok(Self::default()),
+            "light" => Ok(Self {
+                background: [0xff, 0xff, 0xff],
+                colours: [
+                    [0x22, 0x22, 0x22],
+                    [0xcc, 0x00, 0x00],
+                    [0x00, 0x88, 0x00],
+                    [0xcc, 0x99, 0x00],
+                    [0x00, 0x44, 0xcc],
+                    [0x99, 0x00, 0x99],
+                    [0x00, 0x88, 0x88],
+                    [0x00, 0x00, 0x00],
+                    [0x99, 0x99, 0x99],
+                ],
+            }),
+            "mono" => Ok(Self {
+                background: [0x00, 0x00, 0x00],
+                colours: [[0xff, 0xff, 0xff]; 9],
+            }),
+            _ => Err(AocError::parse(s, format!("unknown palette, expected one of {}", Self::NAMES.join(", ")))),
+        }
+    }
+}
+
+// Parses a palette override e.g. yellow=#ffcc00
+pub fn parse_colour(s: &str) -> Result<(Colour, [u8; 3]), AocError> {
+    let (name, hex) = s.split_once('=').ok_or_else(|| AocError::parse(s, "expected a colour name=#rrggbb"))?;
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(AocError::parse(s, "expected the colour as #rrggbb"));
+    }
+    let mut rgb = [0; 3];
+    for (i, c) in rgb.iter_mut().enumerate() {
+        *c = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|e| AocError::parse(s, e))?;
+    }
+    Ok((name.parse()?, rgb))
+}
+
+// A frame reduced to one palette index per cell. Spaces and dots are empty cells so they take the background.
+#[derive(Clone)]
+pub struct IndexedFrame {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl IndexedFrame {
+    pub fn new(fb: &FrameBuffer) -> Self {
+        let mut cells = Vec::with_capacity(fb.width() * fb.height());
+        for y in 0..fb.height() {
+            for x in 0..fb.width() {
+                cells.push(match fb.get(x, y) {
+                    Some(c) if c.ch != ' ' && c.ch != '.' => c.colour.index() as u8 + 1,
+                    _ => 0,
+                });
+            }
+        }
+        Self { width: fb.width(), height: fb.height(), cells }
+    }
+
+    // Palette indices of the pixels with each cell drawn as a cell_size square, padded with background to the canvas
+    fn pixels(&self, cell_size: usize, canvas_width: usize, canvas_height: usize) -> Vec<u8> {
+        let mut pixels = vec![0; canvas_width * canvas_height];
+        for y in 0..self.height * cell_size {
+            for x in 0..self.width * cell_size {
+                pixels[y * canvas_width + x] = self.cells[(y / cell_size) * self.width + x / cell_size];
+            }
+        }
+        pixels
+    }
+}
+
+pub fn write_png(path: &Path, fb: &FrameBuffer, palette: &Palette, cell_size: usize) -> Result<(), Box<dyn Error>> {
+    let frame = IndexedFrame::new(fb);
+    let (width, height) = (frame.width * cell_size, frame.height * cell_size);
+    if width == 0 || height == 0 {
+        return Err(format!("{}: cannot write an empty frame", path.display()).into());
+    }
+
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), width as u32, height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette.flatten());
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&frame.pixels(cell_size, width, height))?;
+    writer.finish()?;
+    Ok(())
+}
+
+// Collects frames for an animated gif. The gif can only be written once every frame is known as its size
+// has to fit the largest one, so the frames are kept as palette indices per cell until then.
+#[derive(Default)]
+pub struct GifRecorder {
+    frames: Vec<IndexedFrame>,
+}
+
+impl GifRecorder {
+    pub fn push(&mut self, fb: &FrameBuffer) {
+        self.frames.push(IndexedFrame::new(fb));
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    // delay is how long each frame shows for in hundredths of a second
+    pub fn write(&self, path: &Path, palette: &Palette, cell_size: usize, delay: u16) -> Result<(), Box<dyn Error>> {
+        let width = self.frames.iter().map(|f| f.width).max().unwrap_or(0) * cell_size;
+        let height = self.frames.iter().map(|f| f.height).max().unwrap_or(0) * cell_size;
+        if width == 0 || height == 0 {
+            return Err(format!("{}: no frames to write", path.display()).into());
+        }
+        let too_big = || format!("{}: frames of {}x{} pixels are too big for a gif, use a smaller cell size", path.display(), width, height);
+        let w = u16::try_from(width).map_err(|_| too_big())?;
+        let h = u16::try_from(height).map_err(|_| too_big())?;
+
+        let mut encoder = gif::Encoder::new(BufWriter::new(File::create(path)?), w, h, &palette.flatten())?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for f in &self.frames {
+            let mut frame = gif::Frame::from_indexed_pixels(w, h, f.pixels(cell_size, width, height), None);
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+}
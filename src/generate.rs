@@ -0,0 +1,111 @@
+// Random but valid puzzle inputs for stress testing the days beyond their one sample and actual input.
+// Each generator takes a seeded Rng, so the same seed always gives the same input, and a size that
+// scales the input, roughly the number of items or the side of a grid. Every generator keeps to the
+// promises the puzzle makes about its input e.g. 2022 day 15 always has exactly one spot for the
+// distress beacon, so both stages of a day must always give an answer for a generated input.
+
+use crate::days;
+use crate::error::AocError;
+use crate::input_reader::InputReader;
+
+mod year_2022;
+
+// splitmix64, small and good enough for inputs while staying the same on every platform and release
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // A value from lo to hi inclusive
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + (self.next_u64() % ((hi - lo) as u64 + 1)) as i64
+    }
+
+    // A value from 0 to n exclusive
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// A generated input with the params the day needs to solve it, as would be in its .params file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub text: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl Generated {
+    pub fn new(text: String) -> Self {
+        Self { text, params: Vec::new() }
+    }
+
+    pub fn with_param(mut self, key: &str, value: impl ToString) -> Self {
+        self.params.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    // The params in the .params file format
+    pub fn params_text(&self) -> String {
+        self.params.iter().map(|(k, v)| format!("{}={}\n", k, v)).collect()
+    }
+
+    pub fn reader(&self, stage: &str) -> InputReader {
+        InputReader::from_text("<generated>", self.text.clone(), stage.to_string()).with_params(self.params.clone())
+    }
+}
+
+pub type Generator = fn(&mut Rng, usize) -> Generated;
+
+pub struct GeneratorInfo {
+    pub year: u32,
+    pub day: u32,
+    pub generate: Generator,
+}
+
+const YEARS: &[&[GeneratorInfo]] = &[year_2022::GENERATORS];
+
+pub fn list() -> impl Iterator<Item = &'static GeneratorInfo> {
+    YEARS.iter().flat_map(|g| g.iter())
+}
+
+// Accepts the year and day the same way as days::find_day
+pub fn find_generator(year: &str, day: &str) -> Result<&'static GeneratorInfo, AocError> {
+    let info = days::find_day(year, day)?;
+    list().find(|g| g.year == info.year && g.day == info.day).ok_or_else(|| AocError::UnknownDay {
+        year: info.year_name(),
+        day: info.day_name(),
+        available: list().filter(|g| g.year == info.year).map(|g| format!("{:02}", g.day)).collect(),
+    })
+}
+
+pub fn generate(year: &str, day: &str, seed: u64, size: usize) -> Result<Generated, AocError> {
+    let info = find_generator(year, day)?;
+    Ok((info.generate)(&mut Rng::new(seed), size.max(1)))
+}
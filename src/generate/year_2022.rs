@@ -0,0 +1,703 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{Generated, GeneratorInfo, Rng};
+
+macro_rules! generators {
+    ($($day:literal => $generate:ident),* $(,)?) => {
+        pub const GENERATORS: &[GeneratorInfo] = &[$(GeneratorInfo { year: 2022, day: $day, generate: $generate }),*];
+    };
+}
+
+generators!(
+    1 => day_01,
+    2 => day_02,
+    3 => day_03,
+    4 => day_04,
+    5 => day_05,
+    6 => day_06,
+    7 => day_07,
+    8 => day_08,
+    9 => day_09,
+    10 => day_10,
+    11 => day_11,
+    12 => day_12,
+    13 => day_13,
+    14 => day_14,
+    15 => day_15,
+    16 => day_16,
+    17 => day_17,
+    18 => day_18,
+    19 => day_19,
+    20 => day_20,
+    21 => day_21,
+    22 => day_22,
+    23 => day_23,
+    24 => day_24,
+    25 => day_25,
+);
+
+const LOWER: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+fn lines(lines: Vec<String>) -> Generated {
+    Generated::new(lines.join("\n") + "\n")
+}
+
+fn word(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| rng.pick(LOWER) as char).collect()
+}
+
+// size elves carrying a few snacks each
+fn day_01(rng: &mut Rng, size: usize) -> Generated {
+    let elves: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.range(1, 6)).map(|_| rng.range(1000, 20000).to_string()).collect();
+            snacks.join("\n")
+        })
+        .collect();
+    Generated::new(elves.join("\n\n") + "\n")
+}
+
+// size * 10 rounds
+fn day_02(rng: &mut Rng, size: usize) -> Generated {
+    lines((0..size * 10).map(|_| format!("{} {}", rng.pick(b"ABC") as char, rng.pick(b"XYZ") as char)).collect())
+}
+
+// size groups of three rucksacks. Each rucksack has one item in both compartments and each group has
+// one badge, so every group uses its own letters for everything else.
+fn day_03(rng: &mut Rng, size: usize) -> Generated {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut rucksacks = Vec::new();
+    for _ in 0..size {
+        rng.shuffle(&mut items);
+        let (badge, rest) = (items[0], &items[1..]);
+        for pool in rest.chunks(17) {
+            let (shared, first, second) = (pool[0], &pool[1..9], &pool[9..17]);
+            let half = rng.range(2, 12) as usize;
+            let mut a = vec![shared, badge];
+            let mut b = vec![shared];
+            if rng.chance(20) {
+                a = vec![badge];
+                b = vec![badge];
+            }
+            a.extend((a.len()..half).map(|_| rng.pick(first)));
+            b.extend((b.len()..half).map(|_| rng.pick(second)));
+            rng.shuffle(&mut a);
+            rng.shuffle(&mut b);
+            rucksacks.push(a.into_iter().chain(b).collect());
+        }
+    }
+    lines(rucksacks)
+}
+
+// size * 10 pairs of sections
+fn day_04(rng: &mut Rng, size: usize) -> Generated {
+    lines(
+        (0..size * 10)
+            .map(|_| {
+                let (a, b) = (rng.range(1, 99), rng.range(1, 99));
+                let (c, d) = (rng.range(1, 99), rng.range(1, 99));
+                format!("{}-{},{}-{}", a.min(b), a.max(b), c.min(d), c.max(d))
+            })
+            .collect(),
+    )
+}
+
+// Up to nine stacks and size * 2 moves. Moves never empty a stack so there is always a crate on top.
+fn day_05(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(3, 9);
+    let mut stacks: Vec<Vec<char>> = (0..count)
+        .map(|_| (0..rng.range(2, size as i64 + 2)).map(|_| (b'A' + rng.below(26) as u8) as char).collect())
+        .collect();
+
+    let mut drawing = Vec::new();
+    let height = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    for row in (0..height).rev() {
+        let crates: Vec<String> = stacks.iter().map(|s| s.get(row).map_or(String::from("   "), |c| format!("[{}]", c))).collect();
+        drawing.push(crates.join(" "));
+    }
+    drawing.push((1..=count).map(|i| format!(" {} ", i)).collect::<Vec<String>>().join(" "));
+
+    let mut moves = Vec::new();
+    while moves.len() < size * 2 {
+        let (from, to) = (rng.below(count), rng.below(count));
+        if from == to || stacks[from].len() < 2 {
+            continue;
+        }
+        let left = stacks[from].len() - rng.range(1, (stacks[from].len() - 1).min(99) as i64) as usize;
+        let moved = stacks[from].split_off(left);
+        let n = moved.len();
+        stacks[to].extend(moved);
+        moves.push(format!("move {} from {} to {}", n, from + 1, to + 1));
+    }
+    Generated::new(drawing.join("\n") + "\n\n" + &moves.join("\n") + "\n")
+}
+
+// size * 20 characters ending with a start of message marker
+fn day_06(rng: &mut Rng, size: usize) -> Generated {
+    let mut marker = LOWER.to_vec();
+    rng.shuffle(&mut marker);
+    let text: String = (0..size * 20).map(|_| rng.pick(&LOWER[..8]) as char).chain(marker[..14].iter().map(|&c| c as char)).collect();
+    Generated::new(text + "\n")
+}
+
+struct Dir {
+    name: String,
+    files: Vec<(String, u64)>,
+    dirs: Vec<usize>,
+}
+
+// Around size directories. The disk is always full enough that something has to be deleted.
+fn day_07(rng: &mut Rng, size: usize) -> Generated {
+    let mut dirs = vec![Dir { name: String::from("/"), files: Vec::new(), dirs: Vec::new() }];
+    for i in 1..size.max(2) {
+        let parent = rng.below(i);
+        let name = format!("{}{}", word(rng, 3), i);
+        dirs.push(Dir { name, files: Vec::new(), dirs: Vec::new() });
+        dirs[parent].dirs.push(i);
+    }
+
+    let mut total = 0;
+    for (i, d) in dirs.iter_mut().enumerate() {
+        for j in 0..rng.range(0, 3) {
+            let bytes = rng.range(1000, 40000) as u64;
+            d.files.push((format!("{}{}{}.{}", word(rng, 3), i, j, word(rng, 3)), bytes));
+            total += bytes;
+        }
+    }
+    // The big files go below / as only directories below it are considered for deletion
+    let mut big = 0;
+    while total < 41_000_000 {
+        let bytes = rng.range(1_000_000, 4_000_000) as u64;
+        let i = rng.range(1, dirs.len() as i64 - 1) as usize;
+        dirs[i].files.push((format!("big{}.dat", big), bytes));
+        big += 1;
+        total += bytes;
+    }
+
+    let mut out = vec![String::from("$ cd /")];
+    list_dir(&dirs, 0, &mut out);
+    lines(out)
+}
+
+fn list_dir(dirs: &[Dir], i: usize, out: &mut Vec<String>) {
+    out.push(String::from("$ ls"));
+    out.extend(dirs[i].dirs.iter().map(|&d| format!("dir {}", dirs[d].name)));
+    out.extend(dirs[i].files.iter().map(|(name, size)| format!("{} {}", size, name)));
+    for &d in &dirs[i].dirs {
+        out.push(format!("$ cd {}", dirs[d].name));
+        list_dir(dirs, d, out);
+        out.push(String::from("$ cd .."));
+    }
+}
+
+// A size by size grid of trees
+fn day_08(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(2);
+    lines((0..side).map(|_| (0..side).map(|_| char::from(b'0' + rng.below(10) as u8)).collect()).collect())
+}
+
+// size * 5 moves of up to size steps
+fn day_09(rng: &mut Rng, size: usize) -> Generated {
+    lines((0..size * 5).map(|_| format!("{} {}", rng.pick(b"UDLR") as char, rng.range(1, size as i64))).collect())
+}
+
+// Enough instructions for the 240 cycles the screen needs, size makes the additions bigger
+fn day_10(rng: &mut Rng, size: usize) -> Generated {
+    let mut out = Vec::new();
+    let mut cycles = 0;
+    while cycles < 240 {
+        if rng.chance(30) {
+            out.push(String::from("noop"));
+            cycles += 1;
+        } else {
+            let mut v = 0;
+            while v == 0 {
+                v = rng.range(-(size as i64) - 3, size as i64 + 3);
+            }
+            out.push(format!("addx {}", v));
+            cycles += 2;
+        }
+    }
+    lines(out)
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    op: (char, Option<u64>),
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+// Up to 8 monkeys. The worry levels of part 1 are never reduced by anything but the division by 3,
+// so monkeys that could overflow them in 20 rounds are thrown away and made again.
+fn day_11(rng: &mut Rng, size: usize) -> Generated {
+    let count = (size / 2 + 2).clamp(3, 8);
+    let monkeys = loop {
+        let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19];
+        rng.shuffle(&mut primes);
+        let square = rng.below(count + 1);
+        let monkeys: Vec<Monkey> = (0..count)
+            .map(|i| {
+                let others: Vec<usize> = (0..count).filter(|&j| j != i).collect();
+                let if_true = rng.pick(&others);
+                let others: Vec<usize> = others.into_iter().filter(|&j| j != if_true).collect();
+                Monkey {
+                    items: (0..rng.range(1, 4)).map(|_| rng.range(50, 99) as u64).collect(),
+                    op: match (i == square, rng.chance(50)) {
+                        (true, _) => ('*', None),
+                        (_, true) => ('+', Some(rng.range(1, 8) as u64)),
+                        _ => ('*', Some(rng.range(2, 19) as u64)),
+                    },
+                    test: primes[i],
+                    if_true,
+                    if_false: rng.pick(&others),
+                }
+            })
+            .collect();
+        if worry_fits(monkeys.clone()) {
+            break monkeys;
+        }
+    };
+
+    let blocks: Vec<String> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let items: Vec<String> = m.items.iter().map(|v| v.to_string()).collect();
+            let by = m.op.1.map_or(String::from("old"), |v| v.to_string());
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                i, items.join(", "), m.op.0, by, m.test, m.if_true, m.if_false
+            )
+        })
+        .collect();
+    Generated::new(blocks.join("\n\n") + "\n")
+}
+
+fn worry_fits(mut monkeys: Vec<Monkey>) -> bool {
+    for _ in 0..20 {
+        for m in 0..monkeys.len() {
+            for val in std::mem::take(&mut monkeys[m].items) {
+                let val = match monkeys[m].op {
+                    ('+', Some(v)) => val.checked_add(v),
+                    (_, Some(v)) => val.checked_mul(v),
+                    (_, None) => val.checked_mul(val),
+                };
+                let Some(val) = val.map(|v| v / 3) else { return false };
+                let to = if val % monkeys[m].test == 0 { monkeys[m].if_true } else { monkeys[m].if_false };
+                monkeys[to].items.push(val);
+            }
+        }
+    }
+    true
+}
+
+// A hill size + 26 wide. Neighbouring squares never differ by more than one, so every square can reach
+// the top at E, which is on the top row opposite S.
+fn day_12(rng: &mut Rng, size: usize) -> Generated {
+    let (width, height) = (size + 26, size / 2 + 3);
+
+    // 25 columns step up, the rest stay level
+    let mut steps: Vec<usize> = (1..width).collect();
+    rng.shuffle(&mut steps);
+    let steps: HashSet<usize> = steps.into_iter().take(25).collect();
+    let mut base = vec![0i64; width];
+    for x in 1..width {
+        base[x] = base[x - 1] + steps.contains(&x) as i64;
+    }
+    let mut offset = vec![0i64; height];
+    for y in 1..height {
+        offset[y] = (offset[y - 1] + rng.range(-1, 1)).clamp(-2, 2);
+    }
+
+    lines(
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| match (x, y) {
+                        (0, 0) => 'S',
+                        (x, 0) if x == width - 1 => 'E',
+                        _ => (b'a' + (base[x] + offset[y]).clamp(0, 25) as u8) as char,
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.range(0, 4))
+        .map(|_| if depth > 0 && rng.chance(30) { packet(rng, depth - 1) } else { rng.range(0, 10).to_string() })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+// size pairs of packets
+fn day_13(rng: &mut Rng, size: usize) -> Generated {
+    let pairs: Vec<String> = (0..size).map(|_| format!("{}\n{}", packet(rng, 3), packet(rng, 3))).collect();
+    Generated::new(pairs.join("\n\n") + "\n")
+}
+
+// size paths of rock below the sand source
+fn day_14(rng: &mut Rng, size: usize) -> Generated {
+    let max_y = size as i64 + 10;
+    lines(
+        (0..size)
+            .map(|_| {
+                let (mut x, mut y) = (rng.range(485, 515), rng.range(2, max_y));
+                let mut points = vec![format!("{},{}", x, y)];
+                for i in 0..rng.range(1, 4) {
+                    if i % 2 == 0 {
+                        x = (x + rng.pick(&[-1, 1]) * rng.range(1, 6)).clamp(480, 520);
+                    } else {
+                        y = (y + rng.pick(&[-1, 1]) * rng.range(1, 6)).clamp(2, max_y);
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ")
+            })
+            .collect(),
+    )
+}
+
+// The area is size * 4 across. Sensors are added for random uncovered spots until the hidden distress
+// beacon is the only one left, each placed beyond the spot away from it so it never reaches the beacon.
+fn day_15(rng: &mut Rng, size: usize) -> Generated {
+    let area = size as i64 * 4;
+    let hidden = (rng.range(0, area), rng.range(0, area));
+    let distance = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+
+    let mut sensors: Vec<((i64, i64), i64)> = Vec::new();
+    let mut out = Vec::new();
+    loop {
+        let uncovered: Vec<(i64, i64)> = (0..=area)
+            .flat_map(|x| (0..=area).map(move |y| (x, y)))
+            .filter(|&p| p != hidden && sensors.iter().all(|&(s, r)| distance(s, p) > r))
+            .collect();
+        if uncovered.is_empty() {
+            break;
+        }
+        let spot = rng.pick(&uncovered);
+        let k = rng.range(1, 3);
+        let sensor = (spot.0 + k * (spot.0 - hidden.0), spot.1 + k * (spot.1 - hidden.1));
+        let r = distance(sensor, hidden) - 1;
+        let dx = rng.range(0, r);
+        let beacon = (sensor.0 + rng.pick(&[-1, 1]) * dx, sensor.1 + rng.pick(&[-1, 1]) * (r - dx));
+        sensors.push((sensor, r));
+        out.push(format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0, sensor.1, beacon.0, beacon.1
+        ));
+    }
+    lines(out).with_param("target_line", rng.range(0, area)).with_param("target_area", area)
+}
+
+// size + 2 valves joined as a tree with a few extra tunnels, at most 8 of which have any flow
+fn day_16(rng: &mut Rng, size: usize) -> Generated {
+    let count = (size + 2).min(20);
+    let mut names = vec![String::from("AA")];
+    while names.len() < count {
+        let name: String = (0..2).map(|_| (b'A' + rng.below(26) as u8) as char).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); count];
+    let mut join = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for i in 1..count {
+        join(i, rng.below(i));
+    }
+    for _ in 0..rng.range(0, 3) {
+        join(rng.below(count), rng.below(count));
+    }
+
+    let mut working: Vec<usize> = (1..count).collect();
+    rng.shuffle(&mut working);
+    working.truncate(8);
+
+    lines(
+        (0..count)
+            .map(|i| {
+                let flow = if working.contains(&i) { rng.range(1, 25) } else { 0 };
+                let to: Vec<&str> = tunnels[i].iter().map(|&t| names[t].as_str()).collect();
+                match to.len() {
+                    1 => format!("Valve {} has flow rate={}; tunnel leads to valve {}", names[i], flow, to[0]),
+                    _ => format!("Valve {} has flow rate={}; tunnels lead to valves {}", names[i], flow, to.join(", ")),
+                }
+            })
+            .collect(),
+    )
+}
+
+// size * 4 + 10 jets
+fn day_17(rng: &mut Rng, size: usize) -> Generated {
+    let jets: String = (0..size * 4 + 10).map(|_| rng.pick(b"<>") as char).collect();
+    Generated::new(jets + "\n")
+}
+
+// size * size * 2 cubes in a size sided space
+fn day_18(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(2) as i64;
+    let mut cubes = HashSet::new();
+    let mut out = Vec::new();
+    for _ in 0..side * side * 2 {
+        let cube = (rng.range(1, side), rng.range(1, side), rng.range(1, side));
+        if cubes.insert(cube) {
+            out.push(format!("{},{},{}", cube.0, cube.1, cube.2));
+        }
+    }
+    lines(out)
+}
+
+// size blueprints with costs in the same ranges as the actual input
+fn day_19(rng: &mut Rng, size: usize) -> Generated {
+    lines(
+        (1..=size.min(99))
+            .map(|i| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+                    i,
+                    rng.range(2, 4),
+                    rng.range(2, 4),
+                    rng.range(2, 4),
+                    rng.range(5, 20),
+                    rng.range(2, 4),
+                    rng.range(5, 20),
+                )
+            })
+            .collect(),
+    )
+}
+
+// size * 5 numbers, exactly one of which is 0
+fn day_20(rng: &mut Rng, size: usize) -> Generated {
+    let limit = size as i64 * 100;
+    let mut numbers: Vec<i64> = (0..size * 5)
+        .map(|_| {
+            let n = rng.range(1, limit);
+            if rng.chance(50) { -n } else { n }
+        })
+        .collect();
+    numbers.insert(rng.below(numbers.len() + 1), 0);
+    lines(numbers.iter().map(|n| n.to_string()).collect())
+}
+
+struct Monkeys<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    out: Vec<String>,
+}
+
+impl Monkeys<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name = word(self.rng, 4);
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn shout(&mut self, value: i64) -> String {
+        let name = self.name();
+        self.out.push(format!("{}: {}", name, value));
+        name
+    }
+
+    fn op(&mut self, a: &str, op: char, b: &str) -> String {
+        let name = self.name();
+        self.out.push(format!("{}: {} {} {}", name, a, op, b));
+        name
+    }
+
+    // A tree of monkeys that does not depend on humn, with its value
+    fn constant(&mut self, depth: usize) -> (String, i64) {
+        if depth == 0 || self.rng.chance(30) {
+            let v = self.rng.range(1, 20);
+            return (self.shout(v), v);
+        }
+        let (a, x) = self.constant(depth - 1);
+        let (b, y) = self.constant(depth - 1);
+        let (op, v) = match self.rng.pick(b"+-*/") {
+            b'-' => ('-', x - y),
+            b'*' if (x * y).abs() < 1_000_000 => ('*', x * y),
+            b'/' if y != 0 && x % y == 0 => ('/', x / y),
+            _ => ('+', x + y),
+        };
+        (self.op(&a, op, &b), v)
+    }
+}
+
+// A chain of about size monkeys from humn to root with small trees of constants along it. Every
+// division on the chain is exact for the part 2 answer, so working back from root always finds it.
+fn day_21(rng: &mut Rng, size: usize) -> Generated {
+    let humn = rng.range(1, 1000);
+    let answer = rng.range(1, 1000);
+    let mut m = Monkeys { rng, names: HashSet::new(), out: vec![format!("humn: {}", humn)] };
+
+    let (mut chain, mut v) = (String::from("humn"), answer);
+    for _ in 0..size {
+        let (c, x) = m.constant(2);
+        let left = m.rng.chance(50);
+        let (op, next) = match m.rng.pick(b"+-*/") {
+            b'-' => ('-', if left { v - x } else { x - v }),
+            b'*' if x != 0 && (v * x).abs() < 1_000_000_000_000 => ('*', v * x),
+            b'/' if x != 0 && v % x == 0 => {
+                v /= x;
+                chain = m.op(&chain, '/', &c);
+                continue;
+            },
+            _ => ('+', v + x),
+        };
+        v = next;
+        chain = if left { m.op(&chain, op, &c) } else { m.op(&c, op, &chain) };
+    }
+
+    // The other side of root is made to match the chain at the answer
+    let (c, x) = m.constant(3);
+    let (k, op) = if v >= x { (v - x, '+') } else { (x - v, '-') };
+    let k = m.shout(k);
+    let other = m.op(&c, op, &k);
+    let (a, b) = if m.rng.chance(50) { (chain, other) } else { (other, chain) };
+    m.out.push(format!("root: {} + {}", a, b));
+
+    let mut out = m.out;
+    rng.shuffle(&mut out);
+    lines(out)
+}
+
+// The map folds into a cube the same way as the sample, with faces size / 2 across
+fn day_22(rng: &mut Rng, size: usize) -> Generated {
+    let face = (size / 2).max(2);
+    // The faces of the sample net by column and row
+    let faces: [&[usize]; 3] = [&[2], &[0, 1, 2], &[2, 3]];
+
+    let mut map = Vec::new();
+    for (row, columns) in faces.iter().enumerate() {
+        for y in 0..face {
+            let mut line = " ".repeat(columns[0] * face);
+            for x in 0..(columns.len() * face) {
+                let start = row == 0 && y == 0 && x == 0;
+                line.push(if !start && rng.chance(15) { '#' } else { '.' });
+            }
+            map.push(line);
+        }
+    }
+
+    let mut path = rng.range(1, face as i64 * 4).to_string();
+    for _ in 0..size * 2 {
+        path += &format!("{}{}", rng.pick(b"LR") as char, rng.range(1, face as i64 * 4));
+    }
+    Generated::new(map.join("\n") + "\n\n" + &path + "\n").with_param("cube_layout", "sample")
+}
+
+// A size by size grove with about a third of it planted
+fn day_23(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.max(3);
+    lines((0..side).map(|_| (0..side).map(|_| if rng.chance(30) { '#' } else { '.' }).collect()).collect())
+}
+
+// A valley size wide and about half as high. As with the actual input nothing blows up or down the
+// columns of the entrance and exit, and valleys with no way there, back and there again are made again.
+fn day_24(rng: &mut Rng, size: usize) -> Generated {
+    let (width, height) = (size.max(3), (size / 2).max(3));
+    loop {
+        let mut blizzards = Vec::new();
+        for y in 1..=height {
+            for x in 1..=width {
+                if rng.chance(25) {
+                    let dirs: &[u8] = if x == 1 || x == width { b"<>" } else { b"<>^v" };
+                    blizzards.push((x, y, rng.pick(dirs)));
+                }
+            }
+        }
+        if valley_crossable(width, height, &blizzards) {
+            let mut rows = vec![vec![b'.'; width + 2]; height + 2];
+            for (y, row) in rows.iter_mut().enumerate() {
+                for (x, c) in row.iter_mut().enumerate() {
+                    if x == 0 || x == width + 1 || (y == 0 && x != 1) || (y == height + 1 && x != width) {
+                        *c = b'#';
+                    }
+                }
+            }
+            for &(x, y, d) in &blizzards {
+                rows[y][x] = d;
+            }
+            return lines(rows.into_iter().map(|r| String::from_utf8(r).unwrap()).collect());
+        }
+    }
+}
+
+// Searches every position at every point in the blizzards' cycle, so always finishes
+fn valley_crossable(width: usize, height: usize, blizzards: &[(usize, usize, u8)]) -> bool {
+    let cycle = num::integer::lcm(width, height);
+    let wrap = |v: usize, by: i64, n: usize| ((v as i64 - 1 + by).rem_euclid(n as i64) + 1) as usize;
+    let blocked: Vec<HashSet<(usize, usize)>> = (0..cycle)
+        .map(|t| {
+            let t = t as i64;
+            blizzards
+                .iter()
+                .map(|&(x, y, d)| match d {
+                    b'>' => (wrap(x, t, width), y),
+                    b'<' => (wrap(x, -t, width), y),
+                    b'v' => (x, wrap(y, t, height)),
+                    _ => (x, wrap(y, -t, height)),
+                })
+                .collect()
+        })
+        .collect();
+
+    let (entrance, exit) = ((1, 0), (width, height + 1));
+    let cross = |from: (usize, usize), to: (usize, usize), start: usize| -> Option<usize> {
+        let mut seen = HashSet::from([(from, start % cycle)]);
+        let mut queue = VecDeque::from([(from, start)]);
+        while let Some((p, t)) = queue.pop_front() {
+            if p == to {
+                return Some(t);
+            }
+            let mut next = vec![p, (p.0 + 1, p.1), (p.0 - 1, p.1), (p.0, p.1 + 1)];
+            if p.1 > 0 {
+                next.push((p.0, p.1 - 1));
+            }
+            for n in next {
+                let inside = (1..=width).contains(&n.0) && (1..=height).contains(&n.1);
+                if (inside || n == entrance || n == exit) && !blocked[(t + 1) % cycle].contains(&n) && seen.insert((n, (t + 1) % cycle)) {
+                    queue.push_back((n, t + 1));
+                }
+            }
+        }
+        None
+    };
+
+    cross(entrance, exit, 0)
+        .and_then(|t| cross(exit, entrance, t))
+        .and_then(|t| cross(entrance, exit, t))
+        .is_some()
+}
+
+fn to_snafu(mut n: i64) -> String {
+    let mut digits = Vec::new();
+    while n > 0 {
+        let (digit, carry) = match n % 5 {
+            3 => ('=', 1),
+            4 => ('-', 1),
+            d => ((b'0' + d as u8) as char, 0),
+        };
+        digits.push(digit);
+        n = n / 5 + carry;
+    }
+    digits.iter().rev().collect()
+}
+
+// size * 2 fuel requirements
+fn day_25(rng: &mut Rng, size: usize) -> Generated {
+    lines((0..size * 2).map(|_| to_snafu(rng.range(1, 1_000_000))).collect())
+}
@@ -1,40 +1,241 @@
-use std::fs::{File, canonicalize, read_to_string};
-use std::io::{BufReader, BufRead, Error};
+use std::fs::{canonicalize, read_to_string};
+use std::io::Error;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use crate::days::types::{split_groups, Grid};
+use crate::error::AocError;
+use crate::render::Visualizer;
+
+// Accumulates the time spent inside the reading helpers, which is where the days parse their input.
+// Clones share the same total so it can be read back after the reader has been handed to a day.
+#[derive(Clone, Default)]
+pub struct ParseTimer {
+    total: Arc<Mutex<Duration>>,
+}
+
+impl ParseTimer {
+    pub fn elapsed(&self) -> Duration {
+        *self.total.lock().unwrap()
+    }
+
+    fn time<T>(&self, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let r = f();
+        *self.total.lock().unwrap() += start.elapsed();
+        r
+    }
+}
+
+// Where the puzzle text comes from. Every reading helper works the same whatever the source.
+#[derive(Clone)]
+pub enum InputSource {
+    // <directory>/<filename> e.g. input/2022/01-A
+    Puzzle,
+    // Any file e.g. one generated elsewhere
+    Path(String),
+    // Text already in memory e.g. read from stdin or given on the command line. name is used in messages.
+    Text { name: String, text: Arc<String> },
+}
+
+#[derive(Clone)]
 pub struct InputReader {
     pub filename: String,
     pub directory: String,
     pub stage: String,
+    pub source: InputSource,
+    // key=value pairs from the command line, these take priority over the params file
+    pub params: Vec<(String, String)>,
+    pub parse_timer: ParseTimer,
+    // Off unless the run asked for frames, see Visualizer::show
+    pub visualizer: Visualizer,
 }
 
 impl InputReader {
-    pub fn vec_1d_newln<T: std::str::FromStr>(&self) -> Result<Vec<T>, Error> where <T as std::str::FromStr>::Err: std::fmt::Debug
+    pub fn new(filename: String, directory: String, stage: String) -> Self {
+        Self {
+            filename,
+            directory,
+            stage,
+            source: InputSource::Puzzle,
+            params: Vec::new(),
+            parse_timer: ParseTimer::default(),
+            visualizer: Visualizer::default(),
+        }
+    }
+
+    pub fn with_source(mut self, source: InputSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_params(mut self, params: Vec<(String, String)>) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn with_visualizer(mut self, visualizer: Visualizer) -> Self {
+        self.visualizer = visualizer;
+        self
+    }
+
+    pub fn from_text(name: &str, text: String, stage: String) -> Self {
+        Self::new(String::new(), String::new(), stage).with_source(InputSource::Text {
+            name: name.to_string(),
+            text: Arc::new(text),
+        })
+    }
+
+    pub fn vec_1d_newln<T: std::str::FromStr>(&self) -> Result<Vec<T>, AocError> where <T as std::str::FromStr>::Err: Into<AocError>
+    {
+        self.parse_timer.time(|| {
+            let (file, s) = self.read()?;
+            let mut v = vec![];
+            let mut offset = 0;
+            for line in s.lines() {
+                let start = offset;
+                offset += line.len() + 1;
+                v.push(line.trim().parse().map_err(|e: T::Err| e.into().locate(&file, &s, start, line))?);
+            }
+            Ok(v)
+        })
+    }
+
+    pub fn vec_1d_sep<T: std::str::FromStr>(&self, sep: &String) -> Result<Vec<T>, AocError> where <T as std::str::FromStr>::Err: Into<AocError>
+    {
+        self.parse_timer.time(|| {
+            let (file, s) = self.read()?;
+            let mut v = vec![];
+            let mut offset = 0;
+            for chunk in s.split(sep) {
+                let start = offset;
+                offset += chunk.len() + sep.len();
+                v.push(chunk.trim().parse().map_err(|e: T::Err| e.into().locate(&file, &s, start, chunk))?);
+            }
+            Ok(v)
+        })
+    }
+
+    // One item per group of lines between blank lines, see types::split_groups
+    pub fn groups<T: std::str::FromStr>(&self) -> Result<Vec<T>, AocError> where <T as std::str::FromStr>::Err: Into<AocError>
     {
-        let br = BufReader::new(File::open(self.fullname()?)?);
-        let mut v = vec![];
-        for line in br.lines() {
-            v.push(line?.trim().parse().expect("Type not found"));
+        self.parse_timer.time(|| {
+            let (file, s) = self.read()?;
+            let mut v = vec![];
+            for group in split_groups(&s) {
+                let start = group.as_ptr() as usize - s.as_ptr() as usize;
+                v.push(group.parse().map_err(|e: T::Err| e.into().locate(&file, &s, start, group))?);
+            }
+            Ok(v)
+        })
+    }
+
+    // One cell per character, see Grid::parse e.g. ipr.grid(digit) for a grid of single digit numbers
+    pub fn grid<T>(&self, cell: impl FnMut(char) -> Result<T, AocError>) -> Result<Grid<T>, AocError> {
+        self.parse_timer.time(|| {
+            let (file, s) = self.read()?;
+            Grid::parse(&s, cell).map_err(|e| e.locate(&file, &s, 0, ""))
+        })
+    }
+
+    pub fn whole<T: std::str::FromStr>(&self) -> Result<T, AocError> where <T as std::str::FromStr>::Err: Into<AocError>
+    {
+        self.parse_timer.time(|| {
+            let (file, s) = self.read()?;
+            s.parse().map_err(|e: T::Err| e.into().locate(&file, &s, 0, ""))
+        })
+    }
+
+    pub fn fullname(&self) -> Result<String, Error> {
+        match &self.source {
+            InputSource::Text { name, .. } => Ok(name.to_string()),
+            _ => Ok(canonicalize(self.path())?.into_os_string().into_string().unwrap()),
+        }
+    }
+
+    // The path of the file this reader reads, or the name of in-memory text
+    pub fn path(&self) -> String {
+        match &self.source {
+            InputSource::Puzzle => self.directory.clone() + "/" + &self.filename[..],
+            InputSource::Path(p) => p.to_string(),
+            InputSource::Text { name, .. } => name.to_string(),
+        }
+    }
+
+    // Puzzle tunables that differ between inputs e.g. the target line in 2022 day 15. They are read from
+    // a file next to the input with .params added to its name holding one key=value per line, # starts a comment.
+    pub fn params_path(&self) -> Option<String> {
+        match self.source {
+            InputSource::Text { .. } => None,
+            _ => Some(self.path() + ".params"),
         }
-        Ok(v)
     }
 
-    pub fn vec_1d_sep<T: std::str::FromStr>(&self, sep: &String) -> Result<Vec<T>, Error> where <T as std::str::FromStr>::Err: std::fmt::Debug
+    pub fn param<T: std::str::FromStr>(&self, key: &str) -> Result<Option<T>, AocError> where <T as std::str::FromStr>::Err: Into<AocError>
     {
-        let s = read_to_string(self.fullname()?).unwrap();
-        let mut v = vec![];
-        for line in s.split(sep) {
-            v.push(line.trim().parse().expect("Type not found"));
+        if let Some((_, v)) = self.params.iter().rev().find(|(k, _)| k == key) {
+            return Ok(Some(v.parse().map_err(|e: T::Err| e.into().locate("--param", "", 0, &format!("{}={}", key, v)))?));
+        }
+
+        let path = match self.params_path() {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+        let s = match read_to_string(&path) {
+            Ok(s) => normalise(&s),
+            Err(_) => return Ok(None),
+        };
+        let mut offset = 0;
+        for line in s.lines() {
+            let start = offset;
+            offset += line.len() + 1;
+            let l = line.split('#').next().unwrap().trim();
+            if l.is_empty() {
+                continue;
+            }
+            let (k, v) = parse_param(l).map_err(|e| e.locate(&path, &s, start, line))?;
+            if k == key {
+                return Ok(Some(v.parse().map_err(|e: T::Err| e.into().locate(&path, &s, start, line))?));
+            }
         }
-        Ok(v)
+        Ok(None)
     }
 
-    pub fn whole<T: std::str::FromStr>(&self) -> Result<T, Error> where <T as std::str::FromStr>::Err: std::fmt::Debug
+    pub fn param_or<T: std::str::FromStr>(&self, key: &str, default: T) -> Result<T, AocError> where <T as std::str::FromStr>::Err: Into<AocError>
     {
-        Ok(read_to_string(self.fullname()?).unwrap().parse().expect("Error Reading Whole File"))
+        Ok(self.param(key)?.unwrap_or(default))
     }
 
-    pub fn fullname(&self) -> Result<String, Error> {
-        Ok(canonicalize(self.directory.clone() + "/" + &self.filename[..])?.into_os_string().into_string().unwrap())
+    // Returns the path as given, for messages, along with the normalised text to parse
+    fn read(&self) -> Result<(String, String), AocError> {
+        let file = self.path();
+        if let InputSource::Text { text, .. } = &self.source {
+            return Ok((file, normalise(text)));
+        }
+        match read_to_string(&file) {
+            Ok(s) => Ok((file, normalise(&s))),
+            Err(e) => Err(AocError::MissingFile { file, reason: e.to_string() }),
+        }
     }
 
-}
\ No newline at end of file
+}
+
+// Makes the text the same whichever editor or platform saved it: no byte order mark, \n line endings
+// and no blank lines or newline at the end, so a day never sees an empty last line.
+pub fn normalise(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines: Vec<&str> = text.split('\n').collect();
+    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+// Splits a key=value parameter
+pub fn parse_param(s: &str) -> Result<(String, String), AocError> {
+    match s.split_once('=') {
+        Some((k, v)) if !k.trim().is_empty() => Ok((k.trim().to_string(), v.trim().to_string())),
+        _ => Err(AocError::parse(s, "expected a parameter key=value")),
+    }
+}
@@ -0,0 +1,23 @@
+// The solvers and the tools around them. The advent-of-code-2022 binary is a thin CLI on top of this,
+// other tools can look up a day in the registry and run it against any InputReader e.g.
+//
+//     let ipr = InputReader::from_text("<text>", text, String::from("1"));
+//     let answer = days::run_day("2022", "01", ipr)?;
+
+pub mod input_reader;
+pub mod days;
+pub mod runner;
+pub mod bench;
+pub mod verify;
+pub mod error;
+pub mod output;
+pub mod watch;
+pub mod scaffold;
+pub mod render;
+pub mod export;
+pub mod generate;
+pub mod reference;
+
+pub use days::{types, Day, DayInfo, Solution};
+pub use error::AocError;
+pub use input_reader::{InputReader, InputSource};
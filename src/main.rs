@@ -1,45 +1,528 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::error::Error;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-pub mod input_reader;
-pub mod days;
+use advent_of_code_2022::{bench, days, export, generate, input_reader, output, reference, render, runner, scaffold, verify, watch};
+use advent_of_code_2022::input_reader::InputSource;
+use advent_of_code_2022::output::OutputFormat;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Year to run e.g. 2022
-   #[arg(short, long)]
-   year: String,
+   #[arg(short, long, required = true)]
+   year: Option<String>,
 
     /// Day to run e.g. 01
-   #[arg(short, long)]
-   day: String,
+   #[arg(short, long, required = true)]
+   day: Option<String>,
 
    /// Input type to run (S for sample and A for actual)
-   #[arg(short, long)]
-   input: String,
+   #[arg(short, long, required_unless_present_any = ["file", "stdin", "text"])]
+   input: Option<String>,
+
+   /// Read the input from this file instead of the input directory
+   #[arg(short, long, group = "input_source")]
+   file: Option<String>,
+
+   /// Read the input from stdin
+   #[arg(long, group = "input_source", conflicts_with = "watch")]
+   stdin: bool,
+
+   /// Use this text as the input
+   #[arg(short, long, group = "input_source")]
+   text: Option<String>,
+
+   /// stage number (1,2) or both to parse the input once and run both stages
+   #[arg(short, long, required = true)]
+   stage: Option<String>,
+
+   /// Set a puzzle parameter e.g. target_line=10, overriding the input's .params file
+   #[arg(short, long = "param", value_parser = input_reader::parse_param)]
+   params: Vec<(String, String)>,
+
+   /// How to print the answers, json and csv print one record per stage with the elapsed time
+   #[arg(short, long, value_enum, default_value_t = OutputFormat::Plain)]
+   output: OutputFormat,
+
+   /// Keep running, re-running the day whenever its input or params file changes
+   #[arg(long)]
+   watch: bool,
+
+   /// With --watch also watch the source tree, rebuilding and restarting when it changes
+   #[arg(long, requires = "watch")]
+   source: bool,
+
+   /// Animate the days that can draw themselves in the terminal as they run
+   #[arg(long)]
+   visualize: bool,
+
+   /// Frames per second for --visualize, 0 to go as fast as possible
+   #[arg(long, default_value_t = 10.0)]
+   fps: f64,
+
+   /// Write every frame as a text file into this directory
+   #[arg(long)]
+   frames: Option<String>,
+
+   /// Write every frame as a png into this directory
+   #[arg(long)]
+   png: Option<String>,
+
+   /// Write the frames as an animated gif to this file, playing at --fps
+   #[arg(long)]
+   gif: Option<String>,
+
+   /// Only keep every Nth frame
+   #[arg(long, default_value_t = 1)]
+   frame_step: usize,
+
+   /// Pixels per cell in png and gif images
+   #[arg(long, default_value_t = 4)]
+   cell_size: usize,
+
+   /// Colours for png and gif images, one of dark, light or mono
+   #[arg(long, default_value = "dark")]
+   palette: export::Palette,
 
-   /// stage number (1,2)
-   #[arg(short, long)]
-   stage: String,
+   /// Change a palette colour e.g. yellow=#ffcc00
+   #[arg(long = "colour", value_parser = export::parse_colour)]
+   colours: Vec<(render::Colour, [u8; 3])>,
 
    /// base directory e.g input
    #[arg(short, long, default_value_t = String::from("input"))]
    base_dir: String,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args = Args::parse();
-    let ipr = input_reader::InputReader {
-        filename: args.day.to_string() + "-" + &args.input[..],
-        directory: args.base_dir.to_string() + "/" + &args.year[..],
-        stage: args.stage.to_string(),
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run both stages of every registered day against the sample and actual inputs
+    All {
+        /// Only run this year e.g. 2022
+        #[arg(short, long)]
+        year: Option<String>,
+
+        /// Only run these days, a single day e.g. 05 or a range e.g. 01-10
+        #[arg(short, long)]
+        days: Option<String>,
+
+        /// Only run this input type (S for sample and A for actual)
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// How to print the results, plain prints a summary table
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Plain)]
+        output: OutputFormat,
+
+        /// Number of days to run at once, one per cpu by default
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// base directory e.g input
+        #[arg(short, long, default_value_t = String::from("input"))]
+        base_dir: String,
+    },
+    /// Create the source and empty inputs for a new day, adding its year if needed
+    New {
+        /// Year of the puzzle e.g. 2022
+        #[arg(short, long)]
+        year: u32,
+
+        /// Day of the puzzle e.g. 05
+        #[arg(short, long)]
+        day: u32,
+
+        /// Puzzle title shown by the list command
+        #[arg(short, long, default_value_t = String::new())]
+        title: String,
+
+        /// base directory e.g input, a relative one is in the crate directory like the source
+        #[arg(short, long, default_value_t = String::from("input"))]
+        base_dir: String,
+    },
+    /// Write a random input for a day, the same seed always gives the same input
+    Generate {
+        /// Year of the puzzle e.g. 2022
+        #[arg(short, long)]
+        year: String,
+
+        /// Day of the puzzle e.g. 05
+        #[arg(short, long)]
+        day: String,
+
+        /// Seed for the random numbers
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// How big an input to make, roughly the number of items or the side of a grid
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// Write the input to this file and any params next to it in FILE.params, ready for --file
+        #[arg(long)]
+        out: Option<String>,
+    },
+    /// Check a day against its brute force reference on random inputs, shrinking the first they disagree on
+    Compare {
+        /// Year of the puzzle e.g. 2022
+        #[arg(short, long)]
+        year: String,
+
+        /// Day of the puzzle e.g. 15
+        #[arg(short, long)]
+        day: String,
+
+        /// Number of seeds to try at each size
+        #[arg(long, default_value_t = 20)]
+        seeds: u64,
+
+        /// Largest input size to try, from 1 up
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// List the registered puzzles
+    List {
+        /// Only list this year e.g. 2022
+        #[arg(short, long)]
+        year: Option<String>,
+    },
+    /// Run the days and compare the answers against input/<year>/answers.json
+    Verify {
+        /// Only run this year e.g. 2022
+        #[arg(short, long)]
+        year: Option<String>,
+
+        /// Only run these days, a single day e.g. 05 or a range e.g. 01-10
+        #[arg(short, long)]
+        days: Option<String>,
+
+        /// Only run this input type (S for sample and A for actual)
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Write answers that have no expected value yet into the answers file
+        #[arg(short, long)]
+        record: bool,
+
+        /// Number of days to run at once, one per cpu by default
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// base directory e.g input
+        #[arg(short, long, default_value_t = String::from("input"))]
+        base_dir: String,
+    },
+    /// Time a day repeatedly, reporting parse and solve times per stage
+    Bench {
+        /// Year to run e.g. 2022
+        #[arg(short, long)]
+        year: String,
+
+        /// Day to run e.g. 01
+        #[arg(short, long)]
+        day: String,
+
+        /// Input type to run (S for sample and A for actual)
+        #[arg(short, long, default_value_t = String::from("A"))]
+        input: String,
+
+        /// stage number (1,2) or both to time the stages together, each stage separately if not set
+        #[arg(short, long)]
+        stage: Option<String>,
+
+        /// Untimed runs before measuring
+        #[arg(short, long, default_value_t = 1)]
+        warmup: usize,
+
+        /// Timed runs per stage
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Save the results as a baseline json file
+        #[arg(long)]
+        save: Option<String>,
+
+        /// Compare the results against a saved baseline json file
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// base directory e.g input
+        #[arg(short, long, default_value_t = String::from("input"))]
+        base_dir: String,
+    },
+}
+
+fn main() {
+    if let Err(e) = run(Args::parse()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn check_jobs(jobs: Option<usize>) -> Result<usize, Box<dyn Error>> {
+    match jobs {
+        Some(0) => Err("jobs must be at least 1".into()),
+        Some(j) => Ok(j),
+        None => Ok(runner::default_jobs()),
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        Some(Command::All { year, days, input, output, jobs, base_dir }) => {
+            let filter = runner::Filter {
+                year,
+                days: match days {
+                    Some(d) => Some(runner::Filter::parse_days(&d)?),
+                    None => None,
+                },
+                input,
+            };
+            let results = runner::run_all(&filter, &base_dir, check_jobs(jobs)?);
+            match output {
+                OutputFormat::Plain => runner::print_summary(&results),
+                OutputFormat::Json => println!("{}", output::to_json(&results)),
+                OutputFormat::Csv => print!("{}", output::to_csv(&results)),
+            }
+            return Ok(());
+        },
+        Some(Command::New { year, day, title, base_dir }) => {
+            for path in scaffold::new_day(Path::new(watch::MANIFEST_DIR), &base_dir, year, day, &title)? {
+                println!("Created {}", path);
+            }
+            println!("Rebuild to run year {} day {:02}", year, day);
+            return Ok(());
+        },
+        Some(Command::Generate { year, day, seed, size, out }) => {
+            let generated = generate::generate(&year, &day, seed, size)?;
+            match out {
+                Some(path) => {
+                    std::fs::write(&path, &generated.text)?;
+                    println!("Created {}", path);
+                    if !generated.params.is_empty() {
+                        let params_path = path + ".params";
+                        std::fs::write(&params_path, generated.params_text())?;
+                        println!("Created {}", params_path);
+                    }
+                },
+                None => {
+                    print!("{}", generated.text);
+                    // The params are not part of the input, so they go to stderr as the arguments to run it with
+                    for (k, v) in &generated.params {
+                        eprintln!("-p {}={}", k, v);
+                    }
+                },
+            }
+            return Ok(());
+        },
+        Some(Command::Compare { year, day, seeds, size }) => {
+            let report = reference::compare(&year, &day, seeds, size)?;
+            let Some(m) = report.mismatch else {
+                println!("Agreed with the reference on all {} inputs", report.inputs);
+                return Ok(());
+            };
+            let d = &m.disagreement;
+            println!("Stage {} disagreed with the reference on input {}, seed {} size {}", d.stage, report.inputs, m.seed, m.size);
+            println!("Shrunk from {} to {} bytes:", m.original.text.len(), m.input.text.len());
+            print!("{}", m.input.text);
+            for (k, v) in &m.input.params {
+                println!("-p {}={}", k, v);
+            }
+            match &d.answer {
+                Ok(answer) => println!("Answered {} but the reference answered {}", answer, d.expected),
+                Err(e) => println!("Failed with {} but the reference answered {}", e, d.expected),
+            }
+            std::process::exit(1);
+        },
+        Some(Command::List { year }) => {
+            let rows: Vec<Vec<String>> = days::list_days()
+                .iter()
+                .filter(|info| year.as_ref().is_none_or(|y| *y == info.year_name()))
+                .map(|info| vec![info.year_name(), info.day_name(), info.title.to_string()])
+                .collect();
+            runner::print_table(&["Year", "Day", "Title"], &rows);
+            return Ok(());
+        },
+        Some(Command::Verify { year, days, input, record, jobs, base_dir }) => {
+            let filter = runner::Filter {
+                year,
+                days: match days {
+                    Some(d) => Some(runner::Filter::parse_days(&d)?),
+                    None => None,
+                },
+                input,
+            };
+            let results = verify::verify_all(&filter, &base_dir, record, check_jobs(jobs)?)?;
+            verify::print_report(&results);
+            if results.iter().any(|r| r.is_failure()) {
+                std::process::exit(1);
+            }
+            return Ok(());
+        },
+        Some(Command::Bench { year, day, input, stage, warmup, iterations, save, baseline, base_dir }) => {
+            if iterations == 0 {
+                return Err("iterations must be at least 1".into());
+            }
+            let config = bench::BenchConfig { base_dir, warmup, iterations };
+            let stages = match stage {
+                Some(s) => vec![s],
+                None => runner::STAGES.iter().map(|s| s.to_string()).collect(),
+            };
+
+            let mut results = Vec::new();
+            for s in stages {
+                results.push(bench::bench_stage(&year, &day, &input, &s, &config)?);
+            }
+
+            let baseline = match baseline {
+                Some(path) => Some(bench::load_baseline(&path)?),
+                None => None,
+            };
+            bench::print_report(&results, baseline.as_ref());
+
+            if let Some(path) = save {
+                bench::save_baseline(&path, &results)?;
+                println!("Saved baseline to {}", path);
+            }
+            return Ok(());
+        },
+        None => (),
+    }
+
+    // Without a subcommand clap has already enforced that these are set
+    let (year, day, stage) = (args.year.unwrap(), args.day.unwrap(), args.stage.unwrap());
+    let info = days::find_day(&year, &day)?;
+    let (year, day) = (info.year_name(), info.day_name());
+
+    let source = if let Some(path) = args.file {
+        InputSource::Path(path)
+    } else if args.stdin {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        InputSource::Text { name: String::from("<stdin>"), text: Arc::new(text) }
+    } else if let Some(text) = args.text {
+        InputSource::Text { name: String::from("<text>"), text: Arc::new(text) }
+    } else {
+        InputSource::Puzzle
+    };
+    let input = args.input.unwrap_or_default();
+
+    let ipr = input_reader::InputReader::new(
+        day.to_string() + "-" + &input[..],
+        args.base_dir.to_string() + "/" + &year[..],
+        stage.to_string(),
+    ).with_source(source).with_params(args.params);
+    let visualizer = if args.visualize || args.frames.is_some() || args.png.is_some() || args.gif.is_some() {
+        let mut palette = args.palette;
+        for (colour, rgb) in args.colours {
+            palette.set(colour, rgb);
+        }
+        render::Visualizer::new(render::VisualizeConfig {
+            animate: args.visualize,
+            fps: args.fps,
+            frame_step: args.frame_step,
+            frames_dir: args.frames.map(PathBuf::from),
+            png_dir: args.png.map(PathBuf::from),
+            gif: args.gif.map(PathBuf::from),
+            cell_size: args.cell_size,
+            palette,
+        })?
+    } else {
+        render::Visualizer::default()
+    };
+    let ipr = ipr.with_visualizer(visualizer.clone());
+    // Name the input by where it was read from when it is not one of the puzzle files
+    let input = match ipr.source {
+        InputSource::Puzzle => input,
+        _ => ipr.path(),
     };
 
-    println!("Running Year {0} day {1} stage {2} input {3}", args.year, args.day, args.stage, args.input);
-    println!("{}",days::run_day(args.year.to_string() + &args.day[..], ipr)?);
+    let task = runner::RunTask { year, day, stage, input };
+
+    if args.watch {
+        return watch_day(task, info.title, ipr, args.output, args.source);
+    }
+
+    if args.output == OutputFormat::Plain {
+        println!("Running Year {0} day {1} stage {2} input {3} - {4}", task.year, task.day, task.stage, task.input, info.title);
+        if task.stage == "both" {
+            let (part1, part2) = days::run_both(&task.year, &task.day, ipr)?;
+            println!("{}", part1);
+            println!("{}", part2);
+        } else {
+            println!("{}",days::run_day(&task.year, &task.day, ipr)?);
+        }
+        return visualizer.finish();
+    }
+
+    let results = run_single(task, ipr);
+    match args.output {
+        OutputFormat::Json => println!("{}", output::to_json(&results)),
+        _ => print!("{}", output::to_csv(&results)),
+    }
+    visualizer.finish()?;
+    if results.iter().any(|r| r.status != runner::RunStatus::Ok) {
+        std::process::exit(1);
+    }
 
     Ok(())
 }
 
+fn run_single(task: runner::RunTask, ipr: input_reader::InputReader) -> Vec<runner::RunResult> {
+    if task.stage == "both" {
+        runner::run_reader_both(task, ipr)
+    } else {
+        vec![runner::run_reader(task, ipr)]
+    }
+}
+
+fn watch_day(task: runner::RunTask, title: &str, ipr: input_reader::InputReader, format: OutputFormat, source: bool) -> Result<(), Box<dyn Error>> {
+    // Found before anything is rebuilt, as the running binary is what gets replaced
+    let exe = std::env::current_exe()?;
+
+    let mut files = Vec::new();
+    if !matches!(ipr.source, InputSource::Text { .. }) {
+        files.push(PathBuf::from(ipr.path()));
+    }
+    files.extend(ipr.params_path().map(PathBuf::from));
+    let source_dir = source.then(|| Path::new(watch::MANIFEST_DIR).join("src"));
+    if files.is_empty() && source_dir.is_none() {
+        return Err("nothing to watch, the input is not a file so use --source to watch the source tree".into());
+    }
+    let mut watcher = watch::Watcher::new(files, source_dir);
 
+    loop {
+        watch::clear_screen();
+        let results = run_single(task.clone(), ipr.clone());
+        match format {
+            OutputFormat::Plain => {
+                println!("Running Year {0} day {1} stage {2} input {3} - {4}", task.year, task.day, task.stage, task.input, title);
+                for r in &results {
+                    match r.status {
+                        runner::RunStatus::Ok => println!("{}", r.answer),
+                        _ => println!("Error: {}", r.error),
+                    }
+                    println!("Stage {} took {}", r.task.stage, bench::format_duration(r.elapsed));
+                }
+            },
+            OutputFormat::Json => println!("{}", output::to_json(&results)),
+            OutputFormat::Csv => print!("{}", output::to_csv(&results)),
+        }
+        println!();
+        println!("Watching for changes, Ctrl-C to stop");
+
+        while let watch::Change::Source = watcher.wait() {
+            println!("Source changed, rebuilding");
+            if watch::rebuild()? {
+                watch::restart(&exe)?;
+            }
+            println!("Build failed, still watching for changes");
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde_json::{json, Value};
+
+use crate::runner::{RunResult, RunStatus};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, clap::ValueEnum)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+    Csv,
+}
+
+fn to_record(r: &RunResult) -> Value {
+    json!({
+        "year": r.task.year,
+        "day": r.task.day,
+        "stage": r.task.stage,
+        "input": r.task.input,
+        "status": r.status.as_str(),
+        "answer": if r.status == RunStatus::Ok { Value::String(r.answer.to_string()) } else { Value::Null },
+        "elapsed": r.elapsed.as_secs_f64(),
+        "error": if r.error.is_empty() { Value::Null } else { Value::String(r.error.to_string()) },
+    })
+}
+
+// A json array with one object per result, the elapsed time is in seconds
+pub fn to_json(results: &[RunResult]) -> String {
+    let records: Vec<Value> = results.iter().map(to_record).collect();
+    serde_json::to_string_pretty(&records).unwrap_or_default()
+}
+
+// Quotes a field if it would otherwise break the row, doubling any quotes inside it
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn to_csv(results: &[RunResult]) -> String {
+    let mut out = String::from("year,day,stage,input,status,answer,elapsed,error\n");
+    for r in results {
+        let row = [
+            r.task.year.to_string(),
+            r.task.day.to_string(),
+            r.task.stage.to_string(),
+            r.task.input.to_string(),
+            r.status.as_str().to_string(),
+            r.answer.to_string(),
+            r.elapsed.as_secs_f64().to_string(),
+            r.error.to_string(),
+        ];
+        out += &row.iter().map(|f| csv_field(f)).collect::<Vec<String>>().join(",");
+        out += "\n";
+    }
+    out
+}
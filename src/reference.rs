@@ -0,0 +1,138 @@
+// Differential testing of the optimised days against brute force reference solvers. Random inputs from
+// the generators go to both, and the first input they disagree on is shrunk to as few lines as still
+// disagree, or as few characters for a one line input, so the bug is easier to find.
+
+use crate::days::{self, Day};
+use crate::error::AocError;
+use crate::generate::{self, Generated, Rng};
+use crate::runner::{self, STAGES};
+
+mod year_2022;
+
+pub struct ReferenceInfo {
+    pub year: u32,
+    pub day: u32,
+    pub create: fn() -> Box<dyn Day>,
+    // Params to add to a generated input e.g. to keep the answer small enough to brute force
+    pub params: fn(&mut Rng) -> Vec<(String, String)>,
+}
+
+const YEARS: &[&[ReferenceInfo]] = &[year_2022::REFERENCES];
+
+pub fn list() -> impl Iterator<Item = &'static ReferenceInfo> {
+    YEARS.iter().flat_map(|r| r.iter())
+}
+
+// Accepts the year and day the same way as days::find_day
+pub fn find_reference(year: &str, day: &str) -> Result<&'static ReferenceInfo, AocError> {
+    let info = days::find_day(year, day)?;
+    list().find(|r| r.year == info.year && r.day == info.day).ok_or_else(|| AocError::UnknownDay {
+        year: info.year_name(),
+        day: info.day_name(),
+        available: list().filter(|r| r.year == info.year).map(|r| format!("{:02}", r.day)).collect(),
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub stage: String,
+    // The day's answer, or why it failed
+    pub answer: Result<String, String>,
+    pub expected: String,
+}
+
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    pub original: Generated,
+    pub input: Generated,
+    pub disagreement: Disagreement,
+}
+
+pub struct CompareReport {
+    // How many inputs were tried, including the one that disagreed
+    pub inputs: usize,
+    pub mismatch: Option<Mismatch>,
+}
+
+// Where the day and its reference first disagree on the input. A stage the reference cannot answer is
+// skipped, as the input is too big to brute force or is not one the puzzle could give.
+pub fn disagreement(reference: &ReferenceInfo, input: &Generated) -> Option<Disagreement> {
+    let (year, day) = (reference.year.to_string(), reference.day.to_string());
+    for stage in STAGES {
+        let (expected, _) = runner::guarded(|| (reference.create)().run(input.reader(stage)));
+        let Ok(expected) = expected else { continue };
+        let (answer, _) = runner::guarded(|| days::run_day(&year, &day, input.reader(stage)));
+        if answer.as_ref() != Ok(&expected) {
+            return Some(Disagreement { stage: stage.to_string(), answer, expected });
+        }
+    }
+    None
+}
+
+// Tries seeds 1 to seeds at every size from 1 to max_size, smallest first, stopping at the first disagreement
+pub fn compare(year: &str, day: &str, seeds: u64, max_size: usize) -> Result<CompareReport, AocError> {
+    let reference = find_reference(year, day)?;
+    let generator = generate::find_generator(year, day)?;
+
+    let mut inputs = 0;
+    for size in 1..=max_size {
+        for seed in 1..=seeds {
+            let mut rng = Rng::new(seed);
+            let mut input = (generator.generate)(&mut rng, size);
+            input.params.extend((reference.params)(&mut rng));
+            inputs += 1;
+
+            if let Some(d) = disagreement(reference, &input) {
+                let shrunk = shrink_input(&input, |i| disagreement(reference, i).is_some_and(|s| s.stage == d.stage));
+                let disagreement = disagreement(reference, &shrunk).unwrap_or(d);
+                return Ok(CompareReport {
+                    inputs,
+                    mismatch: Some(Mismatch { seed, size, original: input, input: shrunk, disagreement }),
+                });
+            }
+        }
+    }
+    Ok(CompareReport { inputs, mismatch: None })
+}
+
+// Shrinks the text of an input, keeping its params
+pub fn shrink_input(input: &Generated, fails: impl Fn(&Generated) -> bool) -> Generated {
+    let with_text = |text: &str| Generated { text: text.to_string(), params: input.params.clone() };
+    with_text(&shrink(&input.text, |text| fails(&with_text(text))))
+}
+
+// Removes ever smaller runs of lines, or characters if there is only one line, for as long as fails
+// still holds. What is left fails, but would not if any one line or character were taken away.
+pub fn shrink(text: &str, fails: impl Fn(&str) -> bool) -> String {
+    // Whatever ends the input is kept rather than shrunk away
+    let body = text.trim_end();
+    let end = &text[body.len()..];
+    let (mut units, sep): (Vec<&str>, &str) = if body.lines().count() > 1 {
+        (body.lines().collect(), "\n")
+    } else {
+        (body.char_indices().map(|(i, c)| &body[i..i + c.len_utf8()]).collect(), "")
+    };
+    let join = |units: &[&str]| units.join(sep) + end;
+
+    let mut chunk = units.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        let mut removed = false;
+        while start < units.len() {
+            let mut candidate = units.clone();
+            candidate.drain(start..(start + chunk).min(units.len()));
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+        // Taking one away can let an earlier one go too, so single units are tried until none can be
+        if chunk > 1 || !removed {
+            chunk /= 2;
+        }
+    }
+    join(&units)
+}
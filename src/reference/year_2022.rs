@@ -0,0 +1,248 @@
+// Each reference solves the puzzle the slow and obvious way, sharing no code with the day it checks.
+// They only need to cope with small inputs and return an error for anything they cannot brute force
+// or that breaks a promise the puzzle makes, which the differential runner then skips.
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use super::ReferenceInfo;
+use crate::days::Day;
+use crate::generate::Rng;
+use crate::input_reader::InputReader;
+
+pub const REFERENCES: &[ReferenceInfo] = &[
+    ReferenceInfo { year: 2022, day: 15, create: || Box::new(Day15 {}), params: |_| Vec::new() },
+    ReferenceInfo { year: 2022, day: 17, create: || Box::new(Day17 {}), params: day_17_params },
+    ReferenceInfo { year: 2022, day: 20, create: || Box::new(Day20 {}), params: |_| Vec::new() },
+    ReferenceInfo { year: 2022, day: 25, create: || Box::new(Day25 {}), params: |_| Vec::new() },
+];
+
+// The integers in a line, wherever they are
+fn numbers(line: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    let mut out = Vec::new();
+    for n in line.split(|c: char| !(c.is_ascii_digit() || c == '-')).filter(|n| !n.is_empty()) {
+        out.push(n.parse()?);
+    }
+    Ok(out)
+}
+
+struct Day15 {}
+
+struct Sensor {
+    at: (i64, i64),
+    beacon: (i64, i64),
+    range: i64,
+}
+
+impl Sensor {
+    fn covers(&self, x: i64, y: i64) -> bool {
+        (self.at.0 - x).abs() + (self.at.1 - y).abs() <= self.range
+    }
+}
+
+impl Day15 {
+    fn sensors(ipr: &InputReader) -> Result<Vec<Sensor>, Box<dyn Error>> {
+        let text: String = ipr.whole()?;
+        let mut sensors = Vec::new();
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            match numbers(line)?[..] {
+                [sx, sy, bx, by] => sensors.push(Sensor { at: (sx, sy), beacon: (bx, by), range: (sx - bx).abs() + (sy - by).abs() }),
+                _ => return Err(format!("expected a sensor and beacon in {}", line).into()),
+            }
+        }
+        Ok(sensors)
+    }
+}
+
+impl Day for Day15 {
+    fn run1(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        let sensors = Self::sensors(&ipr)?;
+        let y: i64 = ipr.param_or("target_line", 2000000)?;
+        let x_min = sensors.iter().map(|s| s.at.0 - s.range).min().unwrap_or(0);
+        let x_max = sensors.iter().map(|s| s.at.0 + s.range).max().unwrap_or(0);
+        if x_max - x_min > 1_000_000 {
+            return Err("the line is too long to check every spot".into());
+        }
+
+        let covered = (x_min..=x_max)
+            .filter(|&x| sensors.iter().any(|s| s.covers(x, y)))
+            .filter(|&x| !sensors.iter().any(|s| s.beacon == (x, y)))
+            .count();
+        Ok(covered.to_string())
+    }
+
+    fn run2(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        let sensors = Self::sensors(&ipr)?;
+        let area: i64 = ipr.param_or("target_area", 4000000)?;
+        if area > 1000 {
+            return Err("the area is too big to check every spot".into());
+        }
+
+        let uncovered: Vec<(i64, i64)> = (0..=area)
+            .flat_map(|x| (0..=area).map(move |y| (x, y)))
+            .filter(|&(x, y)| !sensors.iter().any(|s| s.covers(x, y)))
+            .collect();
+        match uncovered[..] {
+            [(x, y)] => Ok((x * 4000000 + y).to_string()),
+            _ => Err(format!("expected one spot for the distress beacon but found {}", uncovered.len()).into()),
+        }
+    }
+}
+
+struct Day17 {}
+
+// The rocks as offsets from their bottom left corner, y going up
+const ROCKS: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+// A random target so the cycle skipping has to cope with any remainder, small enough to drop every rock
+fn day_17_params(rng: &mut Rng) -> Vec<(String, String)> {
+    vec![(String::from("rock_target"), rng.range(2022, 20000).to_string())]
+}
+
+impl Day17 {
+    fn jets(ipr: &InputReader) -> Result<Vec<i64>, Box<dyn Error>> {
+        let text: String = ipr.whole()?;
+        let jets: Vec<i64> = text.trim().chars().map(|c| if c == '<' { -1 } else { 1 }).collect();
+        if jets.is_empty() || text.trim().chars().any(|c| c != '<' && c != '>') {
+            return Err("expected a line of < and >".into());
+        }
+        Ok(jets)
+    }
+
+    // Drops every rock one at a time and returns the height of the tower
+    fn drop(jets: &[i64], rocks: usize) -> i64 {
+        let mut settled: HashSet<(i64, i64)> = HashSet::new();
+        let mut height = 0;
+        let mut jet = 0;
+        for i in 0..rocks {
+            let rock = ROCKS[i % ROCKS.len()];
+            let (mut x, mut y) = (2, height + 3);
+            let fits = |x: i64, y: i64| rock.iter().all(|(dx, dy)| (0..7).contains(&(x + dx)) && y + dy >= 0 && !settled.contains(&(x + dx, y + dy)));
+            loop {
+                let push = jets[jet % jets.len()];
+                jet += 1;
+                if fits(x + push, y) {
+                    x += push;
+                }
+                if !fits(x, y - 1) {
+                    break;
+                }
+                y -= 1;
+            }
+            for (dx, dy) in rock {
+                settled.insert((x + dx, y + dy));
+                height = height.max(y + dy + 1);
+            }
+        }
+        height
+    }
+}
+
+impl Day for Day17 {
+    fn run1(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        Ok(Self::drop(&Self::jets(&ipr)?, 2022).to_string())
+    }
+
+    fn run2(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        let rocks: usize = ipr.param_or("rock_target", 1000000000000)?;
+        if rocks > 100000 {
+            return Err("too many rocks to drop one at a time".into());
+        }
+        Ok(Self::drop(&Self::jets(&ipr)?, rocks).to_string())
+    }
+}
+
+struct Day20 {}
+
+impl Day20 {
+    // Moves each number along a plain list, by index as the numbers repeat
+    fn mix(ipr: &InputReader, key: i64, rounds: usize) -> Result<String, Box<dyn Error>> {
+        let text: String = ipr.whole()?;
+        let mut numbers = Vec::new();
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            numbers.push(line.trim().parse::<i64>()? * key);
+        }
+        if numbers.len() < 2 || numbers.iter().filter(|&&n| n == 0).count() != 1 {
+            return Err("expected at least two numbers, exactly one of which is 0".into());
+        }
+
+        let n = numbers.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        for _ in 0..rounds {
+            for (i, v) in numbers.iter().enumerate() {
+                let pos = order.iter().position(|&j| j == i).unwrap();
+                order.remove(pos);
+                let to = (pos as i64 + v).rem_euclid(n as i64 - 1) as usize;
+                order.insert(to, i);
+            }
+        }
+
+        let zero = order.iter().position(|&j| numbers[j] == 0).unwrap();
+        let sum: i64 = [1000, 2000, 3000].iter().map(|k| numbers[order[(zero + k) % n]]).sum();
+        Ok(sum.to_string())
+    }
+}
+
+impl Day for Day20 {
+    fn run1(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        Self::mix(&ipr, 1, 1)
+    }
+
+    fn run2(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        Self::mix(&ipr, 811589153, 10)
+    }
+}
+
+struct Day25 {}
+
+impl Day for Day25 {
+    // Adds the numbers a column at a time without ever converting them to integers
+    fn run1(&self, ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        let text: String = ipr.whole()?;
+        let mut columns: Vec<i64> = Vec::new();
+        for line in text.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            for (i, c) in line.chars().rev().enumerate() {
+                let digit = match c {
+                    '2' => 2,
+                    '1' => 1,
+                    '0' => 0,
+                    '-' => -1,
+                    '=' => -2,
+                    _ => return Err(format!("{} is not a snafu number", line).into()),
+                };
+                if columns.len() <= i {
+                    columns.push(0);
+                }
+                columns[i] += digit;
+            }
+        }
+
+        let mut digits = Vec::new();
+        let mut carry = 0;
+        let mut i = 0;
+        while i < columns.len() || carry != 0 {
+            let v = columns.get(i).unwrap_or(&0) + carry;
+            let d = (v + 2).rem_euclid(5) - 2;
+            carry = (v - d) / 5;
+            digits.push(d);
+            i += 1;
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.last().is_some_and(|&d| d < 0) {
+            return Err("the total is negative".into());
+        }
+        Ok(digits.iter().rev().map(|d| ['=', '-', '0', '1', '2'][(d + 2) as usize]).collect())
+    }
+
+    fn run2(&self, _ipr: InputReader) -> Result<String, Box<dyn Error>> {
+        Err("there is no part 2".into())
+    }
+}
@@ -0,0 +1,308 @@
+use std::error::Error;
+use std::fs::{create_dir_all, write};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::AocError;
+use crate::export::{self, GifRecorder, Palette};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Colour {
+    #[default]
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    pub const ALL: [Colour; 9] = [
+        Self::Default,
+        Self::Red,
+        Self::Green,
+        Self::Yellow,
+        Self::Blue,
+        Self::Magenta,
+        Self::Cyan,
+        Self::White,
+        Self::Grey,
+    ];
+
+    // Position in ALL, used to index palettes
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|c| c == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Default => "default",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Yellow => "yellow",
+            Self::Blue => "blue",
+            Self::Magenta => "magenta",
+            Self::Cyan => "cyan",
+            Self::White => "white",
+            Self::Grey => "grey",
+        }
+    }
+
+    // ANSI foreground colour
+    fn code(&self) -> &str {
+        match self {
+            Self::Default => "39",
+            Self::Red => "31",
+            Self::Green => "32",
+            Self::Yellow => "33",
+            Self::Blue => "34",
+            Self::Magenta => "35",
+            Self::Cyan => "36",
+            Self::White => "97",
+            Self::Grey => "90",
+        }
+    }
+}
+
+impl std::str::FromStr for Colour {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        Self::ALL.into_iter().find(|c| c.name() == s || (s == "gray" && *c == Self::Grey)).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|c| c.name()).collect();
+            AocError::parse(&s, format!("unknown colour, expected one of {}", names.join(", ")))
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cell {
+    pub ch: char,
+    pub colour: Colour,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self { ch: ' ', colour: Colour::Default }
+    }
+}
+
+// A fixed size grid of coloured characters, x is the column and y the row from the top
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FrameBuffer {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl FrameBuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, ' ')
+    }
+
+    pub fn filled(width: usize, height: usize, ch: char) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell { ch, colour: Colour::Default }; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Anything drawn outside the buffer is dropped, so callers can draw without clipping themselves
+    pub fn set(&mut self, x: usize, y: usize, ch: char, colour: Colour) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = Cell { ch, colour };
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Cell> {
+        if x < self.width && y < self.height {
+            Some(self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // Writes s along row y starting at column x
+    pub fn text(&mut self, x: usize, y: usize, s: &str, colour: Colour) {
+        for (i, ch) in s.chars().enumerate() {
+            self.set(x + i, y, ch, colour);
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn to_text(&self) -> String {
+        let lines: Vec<String> = self.rows().map(|r| r.iter().map(|c| c.ch).collect()).collect();
+        lines.join("\n")
+    }
+
+    // The text with escape codes that colour it in a terminal, only switching colour when it changes
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.rows() {
+            let mut current = Colour::Default;
+            for c in row {
+                if c.colour != current {
+                    out += &format!("\x1B[{}m", c.colour.code());
+                    current = c.colour;
+                }
+                out.push(c.ch);
+            }
+            if current != Colour::Default {
+                out += "\x1B[39m";
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+// Anything that can draw itself as a frame e.g. a day's board or map
+pub trait Render {
+    fn render(&self) -> FrameBuffer;
+}
+
+#[derive(Clone, Debug)]
+pub struct VisualizeConfig {
+    // Animate the frames in the terminal
+    pub animate: bool,
+    // Frames per second when animating and in a gif, 0 to not wait between frames
+    pub fps: f64,
+    // Only use every frame_step'th frame, so long simulations stay a sensible size
+    pub frame_step: usize,
+    // Also write each frame as a text file into this directory
+    pub frames_dir: Option<PathBuf>,
+    // Also write each frame as a png into this directory
+    pub png_dir: Option<PathBuf>,
+    // Also write the frames as an animated gif to this file once the run finishes
+    pub gif: Option<PathBuf>,
+    // Pixels per cell in images
+    pub cell_size: usize,
+    pub palette: Palette,
+}
+
+impl Default for VisualizeConfig {
+    fn default() -> Self {
+        Self {
+            animate: false,
+            fps: 10.0,
+            frame_step: 1,
+            frames_dir: None,
+            png_dir: None,
+            gif: None,
+            cell_size: 4,
+            palette: Palette::default(),
+        }
+    }
+}
+
+// Shows the frames a day renders as it runs. The default one is off and costs nothing, so days can always
+// call show and only render anything when frames were asked for. Clones share the frames seen so far.
+#[derive(Clone, Default)]
+pub struct Visualizer {
+    config: Option<Arc<VisualizeConfig>>,
+    frames: Arc<AtomicUsize>,
+    gif: Arc<Mutex<GifRecorder>>,
+    // The first frame that could not be written, reported by finish
+    error: Arc<Mutex<Option<String>>>,
+}
+
+impl Visualizer {
+    pub fn new(config: VisualizeConfig) -> Result<Self, Box<dyn Error>> {
+        if config.frame_step == 0 || config.cell_size == 0 {
+            return Err("the frame step and cell size must be at least 1".into());
+        }
+        for dir in [&config.frames_dir, &config.png_dir].into_iter().flatten() {
+            create_dir_all(dir)?;
+        }
+        Ok(Self {
+            config: Some(Arc::new(config)),
+            ..Self::default()
+        })
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.load(Ordering::Relaxed)
+    }
+
+    fn failed(&self, e: impl std::fmt::Display) {
+        self.error.lock().unwrap().get_or_insert_with(|| e.to_string());
+    }
+
+    // Frames go to stderr so they never mix with the answer, and a failed write never stops the day
+    pub fn show(&self, r: &impl Render) {
+        let Some(config) = &self.config else { return };
+        let n = self.frames.fetch_add(1, Ordering::Relaxed);
+        if !n.is_multiple_of(config.frame_step) {
+            return;
+        }
+        let n = n / config.frame_step;
+        let frame = r.render();
+
+        if let Some(dir) = &config.frames_dir {
+            if let Err(e) = write(dir.join(format!("frame_{:06}.txt", n)), frame.to_text() + "\n") {
+                self.failed(e);
+            }
+        }
+        if let Some(dir) = &config.png_dir {
+            if let Err(e) = export::write_png(&dir.join(format!("frame_{:06}.png", n)), &frame, &config.palette, config.cell_size) {
+                self.failed(e);
+            }
+        }
+        if config.gif.is_some() {
+            self.gif.lock().unwrap().push(&frame);
+        }
+        if config.animate {
+            // Clear once, then redraw over the last frame to avoid flicker
+            let clear = if n == 0 { "\x1B[2J" } else { "" };
+            let mut err = io::stderr().lock();
+            let _ = write!(err, "{}\x1B[H{}\x1B[J", clear, frame.to_ansi());
+            let _ = err.flush();
+            if config.fps > 0.0 {
+                thread::sleep(Duration::from_secs_f64(1.0 / config.fps));
+            }
+        }
+    }
+
+    // Writes the gif, if one was asked for, and reports any frame that could not be written
+    pub fn finish(&self) -> Result<(), Box<dyn Error>> {
+        let Some(config) = &self.config else { return Ok(()) };
+        if let Some(e) = self.error.lock().unwrap().take() {
+            return Err(e.into());
+        }
+        if let Some(path) = &config.gif {
+            let gif = self.gif.lock().unwrap();
+            if gif.is_empty() {
+                return Err("this day does not draw any frames".into());
+            }
+            // Gif delays are in hundredths of a second
+            let delay = if config.fps > 0.0 { (100.0 / config.fps).round().max(1.0) as u16 } else { 2 };
+            gif.write(path, &config.palette, config.cell_size, delay)?;
+        }
+        Ok(())
+    }
+}
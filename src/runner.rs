@@ -0,0 +1,281 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::bench;
+use crate::days;
+use crate::error::{AocError, Context};
+use crate::input_reader;
+
+pub const STAGES: &[&str] = &["1", "2"];
+pub const INPUTS: &[&str] = &["S", "A"];
+
+#[derive(Clone)]
+pub struct RunTask {
+    pub year: String,
+    pub day: String,
+    pub stage: String,
+    pub input: String,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum RunStatus {
+    Ok,
+    Error,
+    Missing,
+}
+
+impl RunStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ok => "ok",
+            Self::Error => "error",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+pub struct RunResult {
+    pub task: RunTask,
+    pub status: RunStatus,
+    pub answer: String,
+    pub error: String,
+    pub elapsed: Duration,
+}
+
+// Selects which registered puzzles a batch run covers. Unset fields match everything.
+#[derive(Default)]
+pub struct Filter {
+    pub year: Option<String>,
+    pub days: Option<(u32, u32)>,
+    pub input: Option<String>,
+}
+
+impl Filter {
+    // Accepts a single day e.g. "5" or an inclusive range e.g. "01-10"
+    pub fn parse_days(s: &str) -> Result<(u32, u32), AocError> {
+        match s.split_once('-') {
+            Some((start, end)) => Ok((start.trim().parse().context(s)?, end.trim().parse().context(s)?)),
+            None => {
+                let d = s.trim().parse().context(s)?;
+                Ok((d, d))
+            }
+        }
+    }
+
+    fn matches_day(&self, year: &str, day: &str) -> bool {
+        if let Some(y) = &self.year {
+            if y != year {
+                return false;
+            }
+        }
+        if let Some((start, end)) = self.days {
+            let d: u32 = day.parse().unwrap_or(0);
+            if d < start || d > end {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn matches_input(&self, input: &str) -> bool {
+        match &self.input {
+            Some(i) => i == input,
+            None => true,
+        }
+    }
+}
+
+pub fn collect_tasks(filter: &Filter) -> Vec<RunTask> {
+    let mut tasks = Vec::new();
+    for info in days::list_days() {
+        let (year, day) = (info.year_name(), info.day_name());
+        if !filter.matches_day(&year, &day) {
+            continue;
+        }
+        for input in INPUTS {
+            if !filter.matches_input(input) {
+                continue;
+            }
+            for stage in STAGES {
+                tasks.push(RunTask {
+                    year: year.to_string(),
+                    day: day.to_string(),
+                    stage: stage.to_string(),
+                    input: input.to_string(),
+                });
+            }
+        }
+    }
+    tasks
+}
+
+fn panic_message(p: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = p.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = p.downcast_ref::<String>() {
+        s.to_string()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+pub fn run_task(task: RunTask, base_dir: &str) -> RunResult {
+    let ipr = input_reader::InputReader::new(
+        task.day.to_string() + "-" + &task.input[..],
+        base_dir.to_string() + "/" + &task.year[..],
+        task.stage.to_string(),
+    );
+
+    if !Path::new(&ipr.path()).exists() {
+        return RunResult {
+            task,
+            status: RunStatus::Missing,
+            answer: String::new(),
+            error: String::from("input file not found"),
+            elapsed: Duration::ZERO,
+        };
+    }
+
+    run_reader(task, ipr)
+}
+
+// Times f, turning an error or a panic into a message. A panicking day should only fail its own row, not the whole batch.
+pub(crate) fn guarded<T>(f: impl FnOnce() -> Result<T, Box<dyn std::error::Error>>) -> (Result<T, String>, Duration) {
+    let start = Instant::now();
+    let r = panic::catch_unwind(AssertUnwindSafe(|| f().map_err(|e| e.to_string())));
+    let elapsed = start.elapsed();
+    match r {
+        Ok(r) => (r, elapsed),
+        Err(p) => (Err(format!("panicked: {}", panic_message(p))), elapsed),
+    }
+}
+
+fn to_result(task: RunTask, r: Result<String, String>, elapsed: Duration) -> RunResult {
+    let (status, answer, error) = match r {
+        Ok(answer) => (RunStatus::Ok, answer, String::new()),
+        Err(e) => (RunStatus::Error, String::new(), e),
+    };
+
+    RunResult {
+        task,
+        status,
+        answer,
+        error,
+        elapsed,
+    }
+}
+
+// Runs a single stage with a reader that has already been set up e.g. with another input source
+pub fn run_reader(task: RunTask, ipr: input_reader::InputReader) -> RunResult {
+    let (r, elapsed) = guarded(|| days::run_day(&task.year, &task.day, ipr));
+    to_result(task, r, elapsed)
+}
+
+// Runs both stages from one parse of the input. The two results share the elapsed time of the combined run.
+pub fn run_reader_both(task: RunTask, ipr: input_reader::InputReader) -> Vec<RunResult> {
+    let (r, elapsed) = guarded(|| days::run_both(&task.year, &task.day, ipr));
+    let (r1, r2) = match r {
+        Ok((a1, a2)) => (Ok(a1), Ok(a2)),
+        Err(e) => (Err(e.to_string()), Err(e)),
+    };
+    let stage_task = |stage: &str| RunTask {
+        year: task.year.to_string(),
+        day: task.day.to_string(),
+        stage: stage.to_string(),
+        input: task.input.to_string(),
+    };
+    vec![to_result(stage_task("1"), r1, elapsed), to_result(stage_task("2"), r2, elapsed)]
+}
+
+// One worker per cpu, or a single worker if that cannot be found out
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// Runs the tasks on up to jobs worker threads, each taking the next task off a shared queue so a few
+// slow days do not hold up the rest. The results come back in the same order as the tasks.
+pub fn run_tasks(tasks: Vec<RunTask>, base_dir: &str, jobs: usize) -> Vec<RunResult> {
+    let count = tasks.len();
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let done = Mutex::new(Vec::with_capacity(count));
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, count.max(1)) {
+            s.spawn(|| loop {
+                let next = queue.lock().unwrap().next();
+                let Some((i, task)) = next else { break };
+                // run_task catches panics so a failing day never poisons the locks
+                let result = run_task(task, base_dir);
+                done.lock().unwrap().push((i, result));
+            });
+        }
+    });
+
+    let mut done = done.into_inner().unwrap();
+    done.sort_by_key(|(i, _)| *i);
+    done.into_iter().map(|(_, r)| r).collect()
+}
+
+pub fn run_all(filter: &Filter, base_dir: &str, jobs: usize) -> Vec<RunResult> {
+    run_tasks(collect_tasks(filter), base_dir, jobs)
+}
+
+// Prints left aligned columns separated by " | " with a rule under the header
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: &[String]| {
+        let mut line = String::new();
+        for (i, c) in cells.iter().enumerate() {
+            line += &format!("{:<width$}", c, width = widths[i]);
+            if i + 1 < cells.len() {
+                line += " | ";
+            }
+        }
+        println!("{}", line.trim_end());
+    };
+
+    print_row(&header.iter().map(|h| h.to_string()).collect::<Vec<String>>());
+    println!("{}", widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<String>>().join("-+-"));
+    for row in rows {
+        print_row(row);
+    }
+}
+
+pub fn print_summary(results: &[RunResult]) {
+    let header = ["Year", "Day", "Stage", "Input", "Status", "Time", "Answer", "Error"];
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|r| {
+            vec![
+                r.task.year.to_string(),
+                r.task.day.to_string(),
+                r.task.stage.to_string(),
+                r.task.input.to_string(),
+                r.status.as_str().to_string(),
+                bench::format_duration(r.elapsed),
+                r.answer.replace('\n', "\\n"),
+                r.error.replace('\n', "\\n"),
+            ]
+        })
+        .collect();
+    print_table(&header, &rows);
+
+    let count = |s: RunStatus| results.iter().filter(|r| r.status == s).count();
+    println!();
+    println!(
+        "{} run, {} ok, {} error, {} missing",
+        results.len(),
+        count(RunStatus::Ok),
+        count(RunStatus::Error),
+        count(RunStatus::Missing)
+    );
+}
@@ -0,0 +1,86 @@
+use std::error::Error;
+use std::fs::{create_dir_all, read_to_string, write, OpenOptions};
+use std::path::{Path, PathBuf};
+
+// The skeleton every new day starts from, with Day00 and an empty INFO to fill in
+const TEMPLATE: &str = include_str!("days/day_factory/day_00.rs.txt");
+
+fn factory_dir(root: &Path) -> PathBuf {
+    root.join("src").join("days").join("day_factory")
+}
+
+// Adds entry to the identifiers listed in a name!( ... ); macro call, keeping them sorted one per line.
+// Only a call at the start of a line counts, so one mentioned in a comment is left alone.
+fn add_to_list(text: &str, name: &str, entry: &str) -> Result<String, Box<dyn Error>> {
+    let open = format!("{}!(", name);
+    let start = text
+        .match_indices(&open)
+        .map(|(i, _)| i)
+        .find(|&i| i == 0 || text[..i].ends_with('\n'))
+        .ok_or(format!("no {}!() list found", name))?
+        + open.len();
+    let end = start + text[start..].find(')').ok_or(format!("{}!() list is not closed", name))?;
+
+    let mut entries: Vec<&str> = text[start..end].split(',').map(|e| e.trim()).filter(|e| !e.is_empty()).collect();
+    if entries.contains(&entry) {
+        return Ok(text.to_string());
+    }
+    entries.push(entry);
+    entries.sort();
+
+    let list: String = entries.iter().map(|e| format!("    {},\n", e)).collect();
+    Ok(format!("{}\n{}{}", &text[..start], list, &text[end..]))
+}
+
+fn day_source(year: u32, day: u32, title: &str) -> String {
+    TEMPLATE
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("year: 0,", &format!("year: {},", year))
+        .replace("day: 0,", &format!("day: {},", day))
+        .replace("title: \"\",", &format!("title: {:?},", title))
+}
+
+fn create_empty(path: &Path, created: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    if !path.exists() {
+        OpenOptions::new().write(true).create_new(true).open(path)?;
+        created.push(path.display().to_string());
+    }
+    Ok(())
+}
+
+// Creates the source for a new day from the template and registers it, adding the year module first if it
+// is new, which build.rs then picks up, then creates empty sample and actual inputs. root is the crate
+// directory and a relative base_dir is found from it too, so where it is run from does not matter.
+// Returns what was created.
+pub fn new_day(root: &Path, base_dir: &str, year: u32, day: u32, title: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, not {}", day).into());
+    }
+    let mut created = Vec::new();
+
+    let factory = factory_dir(root);
+    let year_name = format!("year_{}", year);
+    let year_file = factory.join(format!("{}.rs", year_name));
+    let day_file = factory.join(&year_name).join(format!("day_{:02}.rs", day));
+    if day_file.exists() {
+        return Err(format!("{} already exists", day_file.display()).into());
+    }
+
+    if !year_file.exists() {
+        write(&year_file, "days!(\n);\n")?;
+        created.push(year_file.display().to_string());
+    }
+
+    create_dir_all(factory.join(&year_name))?;
+    write(&day_file, day_source(year, day, title))?;
+    created.push(day_file.display().to_string());
+    write(&year_file, add_to_list(&read_to_string(&year_file)?, "days", &format!("day_{:02}", day))?)?;
+
+    let input_dir = root.join(base_dir).join(year.to_string());
+    create_dir_all(&input_dir)?;
+    for input in ["S", "A"] {
+        create_empty(&input_dir.join(format!("{:02}-{}", day, input)), &mut created)?;
+    }
+
+    Ok(created)
+}
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::runner::{self, RunResult, RunStatus, RunTask};
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum VerifyStatus {
+    Pass,
+    Fail,
+    Error,
+    Unrecorded,
+    Recorded,
+    Missing,
+}
+
+impl VerifyStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "FAIL",
+            Self::Error => "error",
+            Self::Unrecorded => "unrecorded",
+            Self::Recorded => "recorded",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+pub struct VerifyResult {
+    pub run: RunResult,
+    pub expected: Option<String>,
+    pub status: VerifyStatus,
+}
+
+impl VerifyResult {
+    // A wrong answer, or a day that errors where an answer is known, counts as a regression
+    pub fn is_failure(&self) -> bool {
+        self.status == VerifyStatus::Fail || (self.status == VerifyStatus::Error && self.expected.is_some())
+    }
+}
+
+// Expected answers live in <base_dir>/<year>/answers.json as {"<day>": {"<stage>": {"<input>": "<answer>"}}}
+pub struct AnswerStore {
+    base_dir: String,
+    years: BTreeMap<String, Value>,
+    changed: Vec<String>,
+}
+
+impl AnswerStore {
+    pub fn new(base_dir: &str) -> Self {
+        Self {
+            base_dir: base_dir.to_string(),
+            years: BTreeMap::new(),
+            changed: Vec::new(),
+        }
+    }
+
+    pub fn path(&self, year: &str) -> PathBuf {
+        Path::new(&self.base_dir).join(year).join("answers.json")
+    }
+
+    fn year(&mut self, year: &str) -> Result<&mut Value, Box<dyn Error>> {
+        if !self.years.contains_key(year) {
+            let path = self.path(year);
+            let v = if path.exists() {
+                read_to_string(&path)?.parse()?
+            } else {
+                Value::Object(Map::new())
+            };
+            self.years.insert(year.to_string(), v);
+        }
+        Ok(self.years.get_mut(year).unwrap())
+    }
+
+    pub fn expected(&mut self, task: &RunTask) -> Result<Option<String>, Box<dyn Error>> {
+        let v = self.year(&task.year)?;
+        Ok(v[&task.day][&task.stage][&task.input].as_str().map(String::from))
+    }
+
+    pub fn record(&mut self, task: &RunTask, answer: &str) -> Result<(), Box<dyn Error>> {
+        let v = self.year(&task.year)?;
+        let mut entry = v;
+        for key in [&task.day, &task.stage] {
+            entry = entry
+                .as_object_mut()
+                .ok_or("answers file is not a json object")?
+                .entry(key.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+        }
+        entry
+            .as_object_mut()
+            .ok_or("answers file is not a json object")?
+            .insert(task.input.to_string(), Value::String(answer.to_string()));
+
+        if !self.changed.contains(&task.year) {
+            self.changed.push(task.year.to_string());
+        }
+        Ok(())
+    }
+
+    // Writes back only the years that had answers recorded
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        for year in &self.changed {
+            write(self.path(year), serde_json::to_string_pretty(&self.years[year])? + "\n")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn check(run: RunResult, store: &mut AnswerStore, record: bool) -> Result<VerifyResult, Box<dyn Error>> {
+    let expected = store.expected(&run.task)?;
+    let status = match (run.status, &expected) {
+        (RunStatus::Missing, _) => VerifyStatus::Missing,
+        (RunStatus::Error, _) => VerifyStatus::Error,
+        (RunStatus::Ok, Some(e)) if *e == run.answer => VerifyStatus::Pass,
+        (RunStatus::Ok, Some(_)) => VerifyStatus::Fail,
+        (RunStatus::Ok, None) if record => {
+            store.record(&run.task, &run.answer)?;
+            VerifyStatus::Recorded
+        },
+        (RunStatus::Ok, None) => VerifyStatus::Unrecorded,
+    };
+
+    Ok(VerifyResult { run, expected, status })
+}
+
+pub fn verify_all(filter: &runner::Filter, base_dir: &str, record: bool, jobs: usize) -> Result<Vec<VerifyResult>, Box<dyn Error>> {
+    let mut store = AnswerStore::new(base_dir);
+    let mut results = Vec::new();
+    for run in runner::run_all(filter, base_dir, jobs) {
+        results.push(check(run, &mut store, record)?);
+    }
+    if record {
+        store.save()?;
+    }
+    Ok(results)
+}
+
+pub fn print_report(results: &[VerifyResult]) {
+    let header = ["Year", "Day", "Stage", "Input", "Status", "Answer", "Expected"];
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|r| {
+            let answer = match r.run.status {
+                RunStatus::Ok => r.run.answer.to_string(),
+                _ => r.run.error.to_string(),
+            };
+            vec![
+                r.run.task.year.to_string(),
+                r.run.task.day.to_string(),
+                r.run.task.stage.to_string(),
+                r.run.task.input.to_string(),
+                r.status.as_str().to_string(),
+                answer.replace('\n', "\\n"),
+                r.expected.clone().unwrap_or_default().replace('\n', "\\n"),
+            ]
+        })
+        .collect();
+    runner::print_table(&header, &rows);
+
+    let count = |s: VerifyStatus| results.iter().filter(|r| r.status == s).count();
+    println!();
+    println!(
+        "{} run, {} pass, {} fail, {} error, {} unrecorded, {} recorded, {} missing",
+        results.len(),
+        count(VerifyStatus::Pass),
+        count(VerifyStatus::Fail),
+        count(VerifyStatus::Error),
+        count(VerifyStatus::Unrecorded),
+        count(VerifyStatus::Recorded),
+        count(VerifyStatus::Missing)
+    );
+}
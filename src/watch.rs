@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fs::{metadata, read_dir};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+// Where this crate was built from, used to find the source tree and to rebuild it
+pub const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+pub enum Change {
+    Input,
+    Source,
+}
+
+// Polls the modified times of the watched files. No filesystem notification crate is needed and a
+// puzzle's inputs and sources are few enough that checking them twice a second costs nothing.
+pub struct Watcher {
+    files: Vec<PathBuf>,
+    source_dir: Option<PathBuf>,
+    interval: Duration,
+    inputs: Vec<Option<SystemTime>>,
+    sources: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn walk(dir: &Path, out: &mut Vec<(PathBuf, Option<SystemTime>)>) {
+    let Ok(entries) = read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            walk(&path, out);
+        } else {
+            let m = modified(&path);
+            out.push((path, m));
+        }
+    }
+}
+
+impl Watcher {
+    // A file that does not exist yet is still watched, creating it counts as a change
+    pub fn new(files: Vec<PathBuf>, source_dir: Option<PathBuf>) -> Self {
+        let mut w = Self {
+            files,
+            source_dir,
+            interval: Duration::from_millis(500),
+            inputs: Vec::new(),
+            sources: Vec::new(),
+        };
+        w.inputs = w.input_times();
+        w.sources = w.source_times();
+        w
+    }
+
+    fn input_times(&self) -> Vec<Option<SystemTime>> {
+        self.files.iter().map(|f| modified(f)).collect()
+    }
+
+    fn source_times(&self) -> Vec<(PathBuf, Option<SystemTime>)> {
+        let mut times = Vec::new();
+        if let Some(dir) = &self.source_dir {
+            walk(dir, &mut times);
+            times.sort();
+        }
+        times
+    }
+
+    // Blocks until a watched file changes. A source change wins if both changed as it needs a rebuild anyway.
+    pub fn wait(&mut self) -> Change {
+        loop {
+            thread::sleep(self.interval);
+            let sources = self.source_times();
+            if sources != self.sources {
+                self.sources = sources;
+                self.inputs = self.input_times();
+                return Change::Source;
+            }
+            let inputs = self.input_times();
+            if inputs != self.inputs {
+                self.inputs = inputs;
+                return Change::Input;
+            }
+        }
+    }
+}
+
+pub fn clear_screen() {
+    print!("\x1B[2J\x1B[H");
+}
+
+// Builds the crate with the same profile as the running binary, returning false if the build failed
+pub fn rebuild() -> Result<bool, Box<dyn Error>> {
+    let mut cmd = Command::new(std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo")));
+    cmd.arg("build").arg("--manifest-path").arg(Path::new(MANIFEST_DIR).join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    Ok(cmd.status()?.success())
+}
+
+// Replaces this process with a fresh run of exe given the same arguments, so the rebuilt code is used
+pub fn restart(exe: &Path) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::new(exe);
+    cmd.args(std::env::args_os().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // exec only returns if it failed
+        Err(Box::new(cmd.exec()))
+    }
+    #[cfg(not(unix))]
+    {
+        std::process::exit(cmd.status()?.code().unwrap_or(1));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+
+use advent_of_code_2022::types::{Bounds, Point};
+
+fn p(x: usize, y: usize) -> Point {
+    Point { x, y }
+}
+
+// Shapes whose edges and corners sit at zero, away from zero, at usize::MAX, and a single row, column and cell
+fn cases() -> Vec<Bounds> {
+    vec![
+        Bounds::new(p(0, 0), p(3, 2)).unwrap(),
+        Bounds::new(p(1, 1), p(4, 5)).unwrap(),
+        Bounds::new(p(2, 3), p(6, 4)).unwrap(),
+        Bounds::new(p(0, 0), p(0, 0)).unwrap(),
+        Bounds::new(p(5, 5), p(5, 5)).unwrap(),
+        Bounds::new(p(0, 2), p(4, 2)).unwrap(),
+        Bounds::new(p(2, 0), p(2, 4)).unwrap(),
+        Bounds::new(p(usize::MAX - 3, usize::MAX - 2), p(usize::MAX, usize::MAX)).unwrap(),
+    ]
+}
+
+// Every point inside the bounds and up to two steps outside them
+fn around(b: &Bounds) -> impl Iterator<Item = Point> {
+    b.expand(2).points()
+}
+
+// The neighbours found the slow way, looking at every point in the 3x3 square around
+fn brute_neighbours(b: &Bounds, c: Point, diagonals: bool) -> BTreeSet<Point> {
+    let mut out = BTreeSet::new();
+    for y in c.y.saturating_sub(1)..=c.y.saturating_add(1) {
+        for x in c.x.saturating_sub(1)..=c.x.saturating_add(1) {
+            let (dx, dy) = (x.abs_diff(c.x), y.abs_diff(c.y));
+            let step = if diagonals { dx.max(dy) == 1 } else { dx + dy == 1 };
+            if step && b.contains(p(x, y)) {
+                out.insert(p(x, y));
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn contains_matches_the_corners() {
+    for b in cases() {
+        for c in around(&b) {
+            let inside = b.min().x <= c.x && c.x <= b.max().x && b.min().y <= c.y && c.y <= b.max().y;
+            assert_eq!(b.contains(c), inside, "{:?} {:?}", b, c);
+        }
+    }
+}
+
+#[test]
+fn adjacent_neighbours_at_every_edge_and_corner() {
+    for b in cases() {
+        for c in around(&b) {
+            let found: Vec<Point> = c.get_adjacent_neighbours(&b);
+            let set: BTreeSet<Point> = found.iter().copied().collect();
+            assert_eq!(set.len(), found.len(), "{:?} {:?} has repeats", b, c);
+            assert_eq!(set, brute_neighbours(&b, c, false), "{:?} {:?}", b, c);
+        }
+    }
+}
+
+#[test]
+fn all_neighbours_at_every_edge_and_corner() {
+    for b in cases() {
+        for c in around(&b) {
+            let found: Vec<Point> = c.get_all_neighbours(&b);
+            let set: BTreeSet<Point> = found.iter().copied().collect();
+            assert_eq!(set.len(), found.len(), "{:?} {:?} has repeats", b, c);
+            assert_eq!(set, brute_neighbours(&b, c, true), "{:?} {:?}", b, c);
+        }
+    }
+}
+
+#[test]
+fn neighbours_lead_in_from_just_outside() {
+    // The way into a valley from an entrance in its top wall
+    let valley = Bounds::new(p(1, 1), p(6, 4)).unwrap();
+    assert_eq!(p(1, 0).get_adjacent_neighbours(&valley), vec![p(1, 1)]);
+    assert_eq!(p(6, 5).get_adjacent_neighbours(&valley), vec![p(6, 4)]);
+    assert!(p(0, 0).get_adjacent_neighbours(&valley).is_empty());
+}
+
+#[test]
+fn clamp_gives_the_nearest_point_inside() {
+    for b in cases() {
+        for c in around(&b) {
+            let clamped = b.clamp(c);
+            assert!(b.contains(clamped), "{:?} {:?}", b, c);
+            if b.contains(c) {
+                assert_eq!(clamped, c);
+            }
+            let nearest = b.points().map(|q| q.x.abs_diff(c.x) + q.y.abs_diff(c.y)).min().unwrap();
+            assert_eq!(clamped.x.abs_diff(c.x) + clamped.y.abs_diff(c.y), nearest, "{:?} {:?}", b, c);
+        }
+    }
+}
+
+#[test]
+fn expand_grows_every_side_but_stops_at_the_limits() {
+    let b = Bounds::new(p(1, 3), p(4, 5)).unwrap();
+    assert_eq!(b.expand(2), Bounds::new(p(0, 1), p(6, 7)).unwrap());
+    assert_eq!(b.expand(0), b);
+    let top = Bounds::new(p(usize::MAX - 1, 0), p(usize::MAX, 1)).unwrap();
+    assert_eq!(top.expand(3), Bounds::new(p(usize::MAX - 4, 0), p(usize::MAX, 4)).unwrap());
+}
+
+#[test]
+fn points_cover_the_bounds_once_each() {
+    for b in cases() {
+        let points: Vec<Point> = b.points().collect();
+        let set: BTreeSet<Point> = points.iter().copied().collect();
+        assert_eq!(points.len(), b.width() * b.height(), "{:?}", b);
+        assert_eq!(set.len(), points.len(), "{:?}", b);
+        assert!(points.iter().all(|c| b.contains(*c)), "{:?}", b);
+        assert_eq!(points.first(), Some(&b.min()));
+        assert_eq!(points.last(), Some(&b.max()));
+    }
+}
+
+#[test]
+fn around_is_the_smallest_bounds_holding_the_points() {
+    assert_eq!(Bounds::around([p(3, 1), p(0, 4), p(2, 2)]), Bounds::new(p(0, 1), p(3, 4)));
+    assert_eq!(Bounds::around([p(7, 7)]), Bounds::new(p(7, 7), p(7, 7)));
+    assert_eq!(Bounds::around([]), None);
+}
+
+#[test]
+fn min_after_max_is_refused() {
+    assert_eq!(Bounds::new(p(2, 0), p(1, 0)), None);
+    assert_eq!(Bounds::new(p(0, 2), p(0, 1)), None);
+}
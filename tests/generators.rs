@@ -0,0 +1,65 @@
+use advent_of_code_2022::days;
+use advent_of_code_2022::generate::{self, Rng};
+use advent_of_code_2022::reference;
+use advent_of_code_2022::runner::{self, RunStatus, RunTask};
+
+const SIZE: usize = 6;
+const SEEDS: [u64; 3] = [1, 2, 3];
+const SIZES: [usize; 3] = [1, 3, SIZE];
+
+// Small enough that the brute force references are quick in a debug build
+const REFERENCE_SIZE: usize = 3;
+
+#[test]
+fn generated_inputs_are_solved() {
+    let mut failures = Vec::new();
+    for g in generate::list().filter(|g| g.year == 2022) {
+        let (year, day) = (g.year.to_string(), format!("{:02}", g.day));
+        let reference = reference::find_reference(&year, &day).ok();
+        for size in SIZES {
+            for seed in SEEDS {
+                let mut generated = generate::generate(&year, &day, seed, size).unwrap();
+                let task = RunTask { year: year.clone(), day: day.clone(), stage: String::from("both"), input: format!("seed {} size {}", seed, size) };
+                for r in runner::run_reader_both(task, generated.reader("both")) {
+                    if r.status != RunStatus::Ok {
+                        failures.push(format!("{} day {} stage {} {}: {}", r.task.year, r.task.day, r.task.stage, r.task.input, r.error));
+                    }
+                }
+
+                // The days with a reference must also give its answers
+                let Some(reference) = reference.filter(|_| size <= REFERENCE_SIZE) else { continue };
+                generated.params.extend((reference.params)(&mut Rng::new(seed)));
+                if let Some(d) = reference::disagreement(reference, &generated) {
+                    failures.push(format!(
+                        "{} day {} stage {} seed {} size {}: answered {:?} but the reference answered {:?}",
+                        year, day, d.stage, seed, size, d.answer, d.expected
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{} generated inputs failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn same_seed_gives_same_input() {
+    for g in generate::list() {
+        let (year, day) = (g.year.to_string(), g.day.to_string());
+        let first = generate::generate(&year, &day, 7, SIZE).unwrap();
+        assert_eq!(first, generate::generate(&year, &day, 7, SIZE).unwrap(), "{} day {}", year, day);
+        assert_ne!(first, generate::generate(&year, &day, 8, SIZE).unwrap(), "{} day {}", year, day);
+    }
+}
+
+#[test]
+fn every_2022_day_has_a_generator() {
+    for info in days::list_days().iter().filter(|i| i.year == 2022) {
+        assert!(generate::find_generator(&info.year_name(), &info.day_name()).is_ok(), "2022 day {} has no generator", info.day_name());
+    }
+}
+
+#[test]
+fn unknown_generator_is_an_error() {
+    assert!(generate::find_generator("2021", "01").is_err());
+    assert!(generate::generate("2022", "26", 1, SIZE).is_err());
+}
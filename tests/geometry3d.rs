@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use advent_of_code_2022::days::geometry3d::{self, Box3D};
+use advent_of_code_2022::types::Point3D;
+
+fn p(x: i64, y: i64, z: i64) -> Point3D {
+    Point3D { x, y, z }
+}
+
+fn cube(min: i64, max: i64) -> Box3D {
+    Box3D::new(p(min, min, min), p(max, max, max)).unwrap()
+}
+
+// A 3x3x3 block with the middle cube missing
+fn hollow() -> HashSet<Point3D> {
+    cube(0, 2).points().filter(|c| *c != p(1, 1, 1)).collect()
+}
+
+#[test]
+fn box_volume_and_points() {
+    let b = Box3D::new(p(-1, 0, 2), p(1, 3, 2)).unwrap();
+    assert_eq!(b.volume(), 12);
+    assert_eq!(b.points().count(), 12);
+    assert!(b.points().all(|c| b.contains(&c)));
+    assert_eq!(b.expand(1).unwrap().volume(), 5 * 6 * 3);
+    assert_eq!(Box3D::around(b.points()), Some(b));
+    assert_eq!(Box3D::around([]), None);
+}
+
+#[test]
+fn box_corners_must_not_cross() {
+    assert_eq!(Box3D::new(p(1, 0, 0), p(0, 5, 5)), None);
+    assert_eq!(Box3D::new(p(0, 0, 3), p(5, 5, 2)), None);
+    let b = Box3D::new(p(0, 0, 0), p(4, 2, 6)).unwrap();
+    assert_eq!((b.min(), b.max()), (p(0, 0, 0), p(4, 2, 6)));
+    assert_eq!(b.expand(-1), Box3D::new(p(1, 1, 1), p(3, 1, 5)));
+    // The y side is only 3 cubes across
+    assert_eq!(b.expand(-2), None);
+}
+
+#[test]
+fn box_intersection() {
+    assert_eq!(cube(0, 3).intersection(&cube(2, 5)), Some(cube(2, 3)));
+    assert_eq!(cube(0, 3).intersection(&cube(3, 5)), Some(cube(3, 3)));
+    assert_eq!(cube(0, 3).intersection(&cube(4, 5)), None);
+    assert_eq!(cube(0, 5).intersection(&cube(1, 2)), Some(cube(1, 2)));
+}
+
+#[test]
+fn box_subtraction_leaves_every_other_cube_once() {
+    let b = Box3D::new(p(0, 0, 0), p(4, 3, 5)).unwrap();
+    let others = [cube(1, 2), cube(-2, 1), cube(3, 9), Box3D::new(p(1, -1, 2), p(2, 9, 3)).unwrap(), cube(10, 11), cube(-5, 10)];
+    for other in others {
+        let parts = b.subtract(&other);
+        assert!(parts.len() <= 6);
+        let mut seen = HashSet::new();
+        for c in parts.iter().flat_map(|part| part.points()) {
+            assert!(seen.insert(c), "{:?} is in two parts of {:?} - {:?}", c, b, other);
+        }
+        let expected: HashSet<Point3D> = b.points().filter(|c| !other.contains(c)).collect();
+        assert_eq!(seen, expected, "{:?} - {:?}", b, other);
+    }
+}
+
+#[test]
+fn flood_fill_stays_inside_what_it_may_enter() {
+    let space = cube(0, 2);
+    let filled = geometry3d::flood_fill(p(0, 0, 0), |c| space.contains(c) && *c != p(1, 0, 0));
+    assert_eq!(filled.len(), 26);
+    assert!(!filled.contains(&p(1, 0, 0)));
+}
+
+#[test]
+fn areas() {
+    let two: HashSet<Point3D> = [p(1, 1, 1), p(2, 1, 1)].into_iter().collect();
+    assert_eq!(geometry3d::surface_area(&two), 10);
+    assert_eq!(geometry3d::exterior_area(&two), 10);
+
+    // The six faces round the missing middle are only on the inside
+    assert_eq!(geometry3d::surface_area(&hollow()), 60);
+    assert_eq!(geometry3d::exterior_area(&hollow()), 54);
+    assert_eq!(geometry3d::exterior_area(&HashSet::new()), 0);
+}
+
+#[test]
+fn components() {
+    let mut cubes = hollow();
+    cubes.extend([p(5, 5, 5), p(5, 5, 6), p(-3, 0, 0)]);
+    // Touching at an edge is not joined
+    cubes.insert(p(3, 3, 2));
+
+    let components = geometry3d::connected_components(&cubes);
+    let sizes: Vec<usize> = components.iter().map(|c| c.len()).collect();
+    assert_eq!(sizes, vec![1, 26, 1, 2]);
+
+    let labels = geometry3d::label_components(&cubes);
+    assert_eq!(labels.len(), cubes.len());
+    assert_eq!(labels[&p(-3, 0, 0)], 0);
+    assert_eq!(labels[&p(5, 5, 6)], labels[&p(5, 5, 5)]);
+}
@@ -0,0 +1,73 @@
+use advent_of_code_2022::types::{digit, Grid, Point, Point2};
+
+fn grid(s: &str) -> Grid<char> {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parses_and_displays() {
+    let g = grid("abc\ndef");
+    assert_eq!((g.width(), g.height()), (3, 2));
+    assert_eq!(g[Point { x: 2, y: 1 }], 'f');
+    assert_eq!(g.to_string(), "abc\ndef");
+    assert_eq!(Grid::parse("12\n34", digit).unwrap().values().sum::<u32>(), 10);
+}
+
+#[test]
+fn ragged_or_bad_cells_are_errors() {
+    assert!("abc\nde".parse::<Grid<char>>().is_err());
+    assert!(Grid::parse("12\n3x", digit).is_err());
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+}
+
+#[test]
+fn checked_and_wrapping_lookups() {
+    let g = grid("abc\ndef");
+    assert_eq!(g.get(0, 0), Some(&'a'));
+    assert_eq!(g.get(-1, 0), None);
+    assert_eq!(g.get(3, 0), None);
+    assert_eq!(g.get(0, 2), None);
+    assert_eq!(g.get_wrapped(-1, -1), &'f');
+    assert_eq!(g.get_wrapped(4, 3), &'e');
+}
+
+#[test]
+fn neighbours_stop_at_the_edges() {
+    let g = grid("abc\ndef\nghi");
+    let corner = Point { x: 0, y: 0 };
+    let middle = Point { x: 1, y: 1 };
+    assert_eq!(g.neighbours4(corner).map(|p| g[p]).collect::<String>(), "bd");
+    assert_eq!(g.neighbours8(corner).count(), 3);
+    assert_eq!(g.neighbours4(middle).map(|p| g[p]).collect::<String>(), "bfhd");
+    assert_eq!(g.neighbours8(middle).count(), 8);
+}
+
+#[test]
+fn rows_columns_and_rays() {
+    let g = grid("abc\ndef\nghi");
+    assert_eq!(g.row(1).collect::<String>(), "def");
+    assert_eq!(g.column(2).collect::<String>(), "cfi");
+    assert_eq!(g.rows().map(|r| r.len()).collect::<Vec<_>>(), vec![3, 3, 3]);
+    assert_eq!(g.ray(Point { x: 0, y: 0 }, Point2::new(1, 1)).map(|p| g[p]).collect::<String>(), "ei");
+    assert_eq!(g.ray(Point { x: 2, y: 1 }, Point2::new(-1, 0)).map(|p| g[p]).collect::<String>(), "ed");
+    assert_eq!(g.ray(Point { x: 0, y: 0 }, Point2::new(0, -1)).count(), 0);
+}
+
+#[test]
+fn transforms() {
+    let g = grid("abc\ndef");
+    assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(g.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(g.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(g.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(g.rotate_clockwise().rotate_anticlockwise(), g);
+}
+
+#[test]
+fn find_and_map() {
+    let g = grid("abc\ndSf");
+    assert_eq!(g.find(|c| *c == 'S'), Some(Point { x: 1, y: 1 }));
+    assert_eq!(g.find(|c| *c == 'E'), None);
+    assert_eq!(g.map(|c| c.is_uppercase() as u8).values().sum::<u8>(), 1);
+}
@@ -0,0 +1,147 @@
+use advent_of_code_2022::days;
+use advent_of_code_2022::input_reader::{normalise, InputReader};
+use advent_of_code_2022::types::split_groups;
+
+fn reader(text: &str) -> InputReader {
+    InputReader::from_text("<test>", text.to_string(), String::from("1"))
+}
+
+#[test]
+fn line_endings_are_normalised() {
+    assert_eq!(normalise("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc");
+    assert_eq!(normalise("a\rb"), "a\nb");
+    assert_eq!(normalise("\u{feff}a\nb\n"), "a\nb");
+}
+
+#[test]
+fn trailing_blank_lines_are_stripped() {
+    assert_eq!(normalise("a\nb\n\n  \n\t\n"), "a\nb");
+    assert_eq!(normalise("\n\n"), "");
+    // Indentation and blank lines before the text are part of the puzzle
+    assert_eq!(normalise("\n  a\n"), "\n  a");
+}
+
+#[test]
+fn groups_are_split_on_blank_lines() {
+    assert_eq!(split_groups("1\n2\n\n3\n"), vec!["1\n2", "3"]);
+    assert_eq!(split_groups("\n1\n \n\n\t\n2\n\n"), vec!["1", "2"]);
+    assert_eq!(split_groups("  a\n b\n\nc"), vec!["  a\n b", "c"]);
+    assert!(split_groups("\n\n").is_empty());
+}
+
+#[test]
+fn groups_read_the_same_whatever_the_line_endings() {
+    let lf: Vec<String> = reader("1\n2\n\n3\n4\n").groups().unwrap();
+    let crlf: Vec<String> = reader("\u{feff}1\r\n2\r\n\r\n3\r\n4\r\n\r\n").groups().unwrap();
+    assert_eq!(lf, vec!["1\n2", "3\n4"]);
+    assert_eq!(crlf, lf);
+}
+
+#[test]
+fn group_parse_errors_give_the_line() {
+    let err = reader("1\n\n2\n\nx\n").groups::<u64>().unwrap_err().to_string();
+    assert!(err.starts_with("<test>:5:"), "{}", err);
+}
+
+#[test]
+fn crlf_samples_give_the_same_answers() {
+    // The days that read their input in groups
+    let days = [("2021", "04"), ("2022", "01"), ("2022", "05"), ("2022", "11"), ("2022", "13"), ("2022", "22")];
+    for (year, day) in days {
+        let lf = std::fs::read_to_string(format!("{}/input/{}/{}-S", env!("CARGO_MANIFEST_DIR"), year, day)).unwrap();
+        let crlf = lf.replace('\n', "\r\n");
+        for stage in ["1", "2"] {
+            let run = |text: &str| {
+                let ipr = InputReader::from_text("<sample>", text.to_string(), stage.to_string())
+                    .with_params(vec![(String::from("cube_layout"), String::from("sample"))]);
+                days::run_day(year, day, ipr).unwrap()
+            };
+            assert_eq!(run(&crlf), run(&lf), "{} day {} stage {}", year, day, stage);
+        }
+    }
+}
+
+#[test]
+fn bad_lines_in_a_day_are_located_errors() {
+    let cases = [
+        ("2022", "02", "A Y\nA Q\n", "<test>:2:"),
+        ("2022", "02", "A Y\n\nB X\n", "<test>:2:"),
+        ("2022", "02", "AY\n", "<test>:1:"),
+        ("2022", "25", "1=\n1*0\n", "<test>:2:"),
+        ("2022", "25", "1=\n\n2\n", "<test>:2:"),
+        ("2022", "24", "#.#\n#.#\n", "<test>:1:"),
+    ];
+    for (year, day, text, at) in cases {
+        for stage in ["1", "2"] {
+            if day == "25" && stage == "2" {
+                continue;
+            }
+            let ipr = InputReader::from_text("<test>", text.to_string(), stage.to_string());
+            let err = days::run_day(year, day, ipr).unwrap_err().to_string();
+            assert!(err.starts_with(at), "{} day {} stage {} on {:?}: {}", year, day, stage, text, err);
+        }
+    }
+}
+
+#[test]
+fn short_or_empty_input_is_an_error() {
+    let cases = [
+        ("2022", "01", ""),
+        ("2022", "05", ""),
+        ("2022", "05", "    [D]\n[N] [C]\n 1   2\n\nmove 3 from 1 to 2\n"),
+        ("2022", "05", "[N]\n 1\n\nmove 1 from 1 to 4\n"),
+        ("2022", "06", "abc"),
+        ("2022", "10", "noop\n"),
+        ("2022", "10", "addx 1\n"),
+        ("2022", "13", "[1]\n\n[2]\n"),
+        ("2022", "13", "[1]\n[\"a\"]\n"),
+        ("2022", "16", "Valve BB has flow rate=1; tunnel leads to valve CC\nValve CC has flow rate=0; tunnel leads to valve BB\n"),
+        ("2022", "20", "0\n"),
+        ("2022", "20", "1\n2\n"),
+        ("2022", "21", "root: abcd + efgh\nabcd: 1\n"),
+        ("2022", "21", "abcd: 1\nhumn: 2\n"),
+        ("2021", "01", ""),
+        ("2021", "01", "1\n2\n"),
+        ("2021", "03", ""),
+        ("2021", "03", "101\n10\n"),
+        ("2021", "04", "1,2\n\n3 4\n5 6\n"),
+        ("2021", "09", "19\n91\n"),
+        ("2021", "10", "(]\n\n<>\n"),
+        ("2021", "10", "()\n[<>]\n"),
+        ("2021", "12", "a-b\nb-end\n"),
+        ("2021", "13", "1,1\n"),
+        ("2021", "14", ""),
+        ("2021", "14", "NN\n\nNC -> B\n"),
+    ];
+    for (year, day, text) in cases {
+        let stages: &[&str] = match (year, day) {
+            ("2021", "13") => &["1"],
+            ("2021", "09") => &["2"],
+            ("2021", "10") if text.starts_with("()") => &["2"],
+            ("2022", "21") if text.starts_with("abcd") => &["1"],
+            ("2022", "13") if text.contains("\n\n") => &["1"],
+            ("2021", "01") if !text.is_empty() => &["2"],
+            _ => &["1", "2"],
+        };
+        for stage in stages {
+            let ipr = InputReader::from_text("<test>", text.to_string(), stage.to_string());
+            assert!(days::run_day(year, day, ipr).is_err(), "{} day {} stage {} on {:?}", year, day, stage, text);
+        }
+    }
+}
+
+#[test]
+fn valves_further_away_than_the_time_left_are_never_opened() {
+    // AA leads down a corridor of 34 valves to the only one that is working, 35 steps away
+    let names: Vec<String> = std::iter::once(String::from("AA")).chain((0..35u8).map(|i| format!("{}{}", (b'B' + i / 26) as char, (b'A' + i % 26) as char))).collect();
+    let mut text = String::new();
+    for (i, name) in names.iter().enumerate() {
+        let flow = if i == names.len() - 1 { 5 } else { 0 };
+        let next: Vec<&str> = [i.checked_sub(1), Some(i + 1)].into_iter().flatten().filter_map(|j| names.get(j)).map(String::as_str).collect();
+        text += &format!("Valve {} has flow rate={}; tunnels lead to valves {}\n", name, flow, next.join(", "));
+    }
+    for stage in ["1", "2"] {
+        let ipr = InputReader::from_text("<test>", text.clone(), stage.to_string());
+        assert_eq!(days::run_day("2022", "16", ipr).unwrap(), "0", "stage {}", stage);
+    }
+}
@@ -0,0 +1,80 @@
+use advent_of_code_2022::days::ocr;
+
+fn from_art(art: &[&str]) -> Vec<Vec<bool>> {
+    art.iter().map(|r| r.chars().map(|c| c == '#').collect()).collect()
+}
+
+#[test]
+fn every_known_letter_reads_back() {
+    for height in [6, 10] {
+        let letters = ocr::letters(height);
+        assert!(!letters.is_empty());
+        for c in letters {
+            let rows = ocr::draw_letters(&c.to_string(), height).unwrap();
+            assert_eq!(ocr::read_letters(&rows), Some(c.to_string()), "{} high {}", height, c);
+        }
+    }
+}
+
+#[test]
+fn every_pair_of_letters_reads_back() {
+    // Wide letters such as Y fill the gap, so check every letter next to every other
+    for height in [6, 10] {
+        let letters = ocr::letters(height);
+        for a in &letters {
+            for b in &letters {
+                let text: String = [*a, *b].iter().collect();
+                let rows = ocr::draw_letters(&text, height).unwrap();
+                assert_eq!(ocr::read_letters(&rows), Some(text.clone()), "{} high", height);
+            }
+        }
+    }
+}
+
+#[test]
+fn y_is_five_wide_as_drawn_by_the_puzzles() {
+    // The next letter starts straight after it with no gap
+    let art = [
+        "#...####.",
+        "#...##..#",
+        ".#.#.###.",
+        "..#..#..#",
+        "..#..#..#",
+        "..#..###.",
+    ];
+    assert_eq!(ocr::read_letters(&from_art(&art)), Some(String::from("YB")));
+    assert_eq!(ocr::draw_letters("YB", 6), Some(from_art(&art)));
+}
+
+#[test]
+fn blank_rows_around_the_letters_are_ignored() {
+    let mut rows = vec![Vec::new()];
+    rows.extend(ocr::draw_letters("HI", 6).unwrap());
+    rows.push(vec![false; 9]);
+    assert_eq!(ocr::read_letters(&rows), Some(String::from("HI")));
+}
+
+#[test]
+fn unknown_glyph_falls_back_to_the_drawing() {
+    // An H next to something that is not a letter
+    let art = [
+        "#..#.#..#",
+        "#..#..#..",
+        "####...#.",
+        "#..#..#..",
+        "#..#.#..#",
+        "#..#.....",
+    ];
+    let rows = from_art(&art);
+    assert_eq!(ocr::read_letters(&rows), None);
+    assert_eq!(ocr::read_or_render(&rows), art.join("\n"));
+}
+
+#[test]
+fn unknown_height_or_letter_is_none() {
+    assert_eq!(ocr::read_letters(&from_art(&["#", "#", "#"])), None);
+    assert_eq!(ocr::read_letters(&vec![vec![false; 4]; 6]), None);
+    assert_eq!(ocr::draw_letters("Q", 6), None);
+    assert_eq!(ocr::draw_letters("A", 7), None);
+    assert!(ocr::letters(7).is_empty());
+}
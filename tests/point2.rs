@@ -0,0 +1,53 @@
+use advent_of_code_2022::types::Point2;
+
+#[test]
+fn arithmetic() {
+    let a = Point2::new(3, -2);
+    let b = Point2::new(-1, 5);
+    assert_eq!(a + b, Point2::new(2, 3));
+    assert_eq!(a - b, Point2::new(4, -7));
+    assert_eq!(a * 3, Point2::new(9, -6));
+    assert_eq!(-a, Point2::new(-3, 2));
+    let mut c = a;
+    c += b;
+    c -= Point2::right();
+    assert_eq!(c, Point2::new(1, 3));
+}
+
+#[test]
+fn distances() {
+    let a = Point2::new(3i64, -2);
+    let b = Point2::new(-1, 5);
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.chebyshev(b), 7);
+    // Unsigned points measure the same way
+    assert_eq!(Point2::new(1usize, 9).manhattan(Point2::new(4, 2)), 10);
+    assert_eq!(Point2::new(1usize, 9).chebyshev(Point2::new(4, 2)), 7);
+}
+
+#[test]
+fn turns() {
+    assert_eq!(Point2::<i64>::right().rotate_right(), Point2::down());
+    assert_eq!(Point2::<i64>::right().rotate_left(), Point2::up());
+    let p = Point2::new(2i64, 1);
+    assert_eq!(p.rotate_right().rotate_right().rotate_right().rotate_right(), p);
+    assert_eq!(p.rotate_left().rotate_right(), p);
+    assert_eq!(Point2::new(-7i64, 0).signum(), Point2::left());
+}
+
+#[test]
+fn neighbours() {
+    let p = Point2::new(0i32, 0);
+    assert_eq!(p.neighbours4().collect::<Vec<_>>(), vec![Point2::new(0, -1), Point2::new(1, 0), Point2::new(0, 1), Point2::new(-1, 0)]);
+    let around: Vec<_> = p.neighbours8().collect();
+    assert_eq!(around.len(), 8);
+    assert!(around.iter().all(|n| n.chebyshev(p) == 1));
+    assert!(!around.contains(&p));
+}
+
+#[test]
+fn parses() {
+    assert_eq!("4, -6".parse::<Point2<i64>>().unwrap(), Point2::new(4, -6));
+    assert!("4".parse::<Point2<i64>>().is_err());
+    assert!("4,x".parse::<Point2<i64>>().is_err());
+}
@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use advent_of_code_2022::generate::Generated;
+use advent_of_code_2022::input_reader::InputReader;
+use advent_of_code_2022::reference;
+use advent_of_code_2022::runner::{RunTask, STAGES};
+use advent_of_code_2022::verify::AnswerStore;
+
+// Small enough that dropping every rock of 2022 day 17 is quick in a debug build
+const SEEDS: u64 = 4;
+const MAX_SIZE: usize = 3;
+
+#[test]
+fn days_agree_with_their_references() {
+    for r in reference::list() {
+        let report = reference::compare(&r.year.to_string(), &r.day.to_string(), SEEDS, MAX_SIZE).unwrap();
+        if let Some(m) = report.mismatch {
+            panic!(
+                "{} day {} stage {} answered {:?} but the reference answered {:?} for seed {} size {}, shrunk to\n{}\nwith params {:?}",
+                r.year, r.day, m.disagreement.stage, m.disagreement.answer, m.disagreement.expected, m.seed, m.size, m.input.text, m.input.params
+            );
+        }
+    }
+}
+
+#[test]
+fn references_give_the_recorded_sample_answers() {
+    let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("input").display().to_string();
+    let mut store = AnswerStore::new(&base_dir);
+    for r in reference::list() {
+        let (year, day) = (r.year.to_string(), format!("{:02}", r.day));
+        for stage in STAGES {
+            let task = RunTask { year: year.clone(), day: day.clone(), stage: stage.to_string(), input: String::from("S") };
+            let ipr = InputReader::new(day.clone() + "-S", format!("{}/{}", base_dir, year), stage.to_string());
+            // Some stages are too big to brute force even for the sample
+            let Ok(answer) = (r.create)().run(ipr) else { continue };
+            assert_eq!(Some(answer), store.expected(&task).unwrap(), "{} day {} stage {}", year, day, stage);
+        }
+    }
+}
+
+#[test]
+fn sensor_tip_on_the_target_line_is_counted() {
+    // Only the bottom tip of the sensor's range reaches line 2
+    let input = Generated::new(String::from("Sensor at x=0, y=0: closest beacon is at x=2, y=0\n"))
+        .with_param("target_line", 2)
+        .with_param("target_area", 4);
+    let day_15 = reference::find_reference("2022", "15").unwrap();
+    assert_eq!(reference::disagreement(day_15, &input), None);
+}
+
+#[test]
+fn tower_height_counts_the_whole_tower_not_the_last_rock() {
+    // Found by compare at seed 1 size 1, the part 2 height was worked out from the tops of single rocks
+    let input = Generated::new(String::from("<\n")).with_param("rock_target", 4942);
+    let day_17 = reference::find_reference("2022", "17").unwrap();
+    assert_eq!(reference::disagreement(day_17, &input), None);
+}
+
+#[test]
+fn rock_pattern_repeats_how_much_the_tower_grows() {
+    // Found by compare at seed 5 size 1, the rocks landed in the same columns each lap but not at the same heights
+    let input = Generated::new(String::from(">>><>><><\n")).with_param("rock_target", 6050);
+    let day_17 = reference::find_reference("2022", "17").unwrap();
+    assert_eq!(reference::disagreement(day_17, &input), None);
+}
+
+#[test]
+fn negative_move_of_one_less_than_a_lap() {
+    // -2 moves two to the left in a list of four, which is the same as one to the right
+    let input = Generated::new(String::from("1\n-2\n0\n3\n"));
+    let day_20 = reference::find_reference("2022", "20").unwrap();
+    assert_eq!(reference::disagreement(day_20, &input), None);
+}
+
+#[test]
+fn negative_moves_in_a_generated_list_of_four() {
+    // Found by compare at seed 3 size 1, -67 and -43 times the decryption key are both one short of a whole number of laps
+    let input = Generated::new(String::from("0\n-67\n73\n-43\n"));
+    let day_20 = reference::find_reference("2022", "20").unwrap();
+    assert_eq!(reference::disagreement(day_20, &input), None);
+}
+
+#[test]
+fn shrink_keeps_only_the_lines_needed() {
+    let fails = |t: &str| t.contains('b') && t.contains('e');
+    assert_eq!(reference::shrink("a\nb\nc\nd\ne\nf\n", fails), "b\ne\n");
+}
+
+#[test]
+fn shrink_keeps_only_the_characters_needed_on_one_line() {
+    let fails = |t: &str| t.matches('>').count() >= 2 && t.ends_with('\n');
+    assert_eq!(reference::shrink("<<><<<>>\n", fails), ">>\n");
+}
+
+#[test]
+fn shrink_leaves_an_input_that_always_fails_as_one_unit() {
+    assert_eq!(reference::shrink("a\nb\nc", |_| true).lines().count(), 1);
+}
+
+#[test]
+fn unknown_reference_is_an_error() {
+    assert!(reference::find_reference("2022", "01").is_err());
+    assert!(reference::find_reference("2021", "15").is_err());
+}
@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use advent_of_code_2022::runner::{self, RunTask};
+use advent_of_code_2022::verify::{self, AnswerStore, VerifyStatus};
+
+fn input_dir() -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("input").display().to_string()
+}
+
+// Both stages of every registered day against its sample, so a new day is covered as soon as it is registered
+fn sample_tasks() -> Vec<RunTask> {
+    let filter = runner::Filter {
+        input: Some(String::from("S")),
+        ..Default::default()
+    };
+    runner::collect_tasks(&filter)
+}
+
+#[test]
+fn sample_answers_match() {
+    let base_dir = input_dir();
+    let mut store = AnswerStore::new(&base_dir);
+    let mut failures = Vec::new();
+
+    for run in runner::run_tasks(sample_tasks(), &base_dir, runner::default_jobs()) {
+        let r = verify::check(run, &mut store, false).unwrap();
+        let task = &r.run.task;
+        let problem = match r.status {
+            VerifyStatus::Pass => continue,
+            VerifyStatus::Fail => format!("answered {:?} but expected {:?}", r.run.answer, r.expected.unwrap_or_default()),
+            VerifyStatus::Unrecorded => format!(
+                "answered {:?} but there is no expected answer in {}, record it with verify --record",
+                r.run.answer,
+                store.path(&task.year).display()
+            ),
+            _ => r.run.error.to_string(),
+        };
+        failures.push(format!("{} day {} stage {}: {}", task.year, task.day, task.stage, problem));
+    }
+
+    assert!(failures.is_empty(), "{} sample answers are wrong:\n{}", failures.len(), failures.join("\n"));
+}
@@ -0,0 +1,35 @@
+use std::fs;
+use std::path::PathBuf;
+
+use advent_of_code_2022::scaffold;
+
+// A crate directory of its own under the temp dir, with the one directory new_day expects
+fn scratch_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src").join("days").join("day_factory")).unwrap();
+    root
+}
+
+#[test]
+fn inputs_go_under_the_same_root_as_the_source() {
+    let root = scratch_root("relative");
+    let created = scaffold::new_day(&root, "input", 2030, 7, "Test").unwrap();
+
+    assert!(root.join("src/days/day_factory/year_2030/day_07.rs").is_file());
+    assert!(root.join("input/2030/07-S").is_file());
+    assert!(root.join("input/2030/07-A").is_file());
+    assert!(created.iter().all(|p| PathBuf::from(p).starts_with(&root)), "{:?}", created);
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn absolute_base_dir_is_used_as_given() {
+    let root = scratch_root("absolute");
+    let inputs = root.join("elsewhere");
+    scaffold::new_day(&root, inputs.to_str().unwrap(), 2030, 8, "Test").unwrap();
+
+    assert!(inputs.join("2030/08-S").is_file());
+    assert!(!root.join("input").exists());
+    fs::remove_dir_all(&root).unwrap();
+}
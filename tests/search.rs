@@ -0,0 +1,100 @@
+use advent_of_code_2022::days::search::{astar, bfs, dijkstra, Path};
+use advent_of_code_2022::types::{Grid, Point};
+
+// 1 -> 2 -> 3 -> 4 the long way, 1 -> 5 -> 4 the short way, and 6 can not be reached
+fn edges(n: &u32) -> Vec<u32> {
+    match n {
+        1 => vec![2, 5],
+        2 => vec![3],
+        3 => vec![4],
+        5 => vec![4],
+        _ => Vec::new(),
+    }
+}
+
+// The same graph, but the short way is dearer than the long way
+fn weighted(n: &u32) -> Vec<(u32, u64)> {
+    match n {
+        1 => vec![(2, 1), (5, 4)],
+        2 => vec![(3, 1)],
+        3 => vec![(4, 1)],
+        5 => vec![(4, 4)],
+        _ => Vec::new(),
+    }
+}
+
+// A maze where # is a wall and a digit is what it costs to step onto that cell
+const MAZE: &str = "\
+11111111
+1####1#1
+19111191
+1#1##1#1
+1111#111
+##1#1#11
+11119111";
+
+fn maze() -> Grid<char> {
+    MAZE.parse().unwrap()
+}
+
+// The open cells next to p, each with what it costs to step onto it
+fn maze_steps(grid: &Grid<char>, p: &Point) -> Vec<(Point, u64)> {
+    grid.neighbours4(*p).filter_map(|n| grid[n].to_digit(10).map(|c| (n, u64::from(c)))).collect()
+}
+
+#[test]
+fn bfs_takes_the_fewest_steps() {
+    assert_eq!(bfs(1, edges, |n| *n == 4), Some(Path { cost: 2, nodes: vec![1, 5, 4] }));
+}
+
+#[test]
+fn start_that_is_the_goal_is_a_path_of_one_node() {
+    assert_eq!(bfs(3, edges, |n| *n == 3), Some(Path { cost: 0, nodes: vec![3] }));
+    assert_eq!(dijkstra(3, weighted, |n| *n == 3), Some(Path { cost: 0, nodes: vec![3] }));
+}
+
+#[test]
+fn unreachable_goal_is_none() {
+    assert_eq!(bfs(1, edges, |n| *n == 6), None);
+    assert_eq!(dijkstra(1, weighted, |n| *n == 6), None);
+    assert_eq!(astar(1, weighted, |_| 0, |n| *n == 6), None);
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_way_not_the_shortest() {
+    assert_eq!(dijkstra(1, weighted, |n| *n == 4), Some(Path { cost: 3, nodes: vec![1, 2, 3, 4] }));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra_on_every_goal_in_a_maze() {
+    let grid = maze();
+    let start = Point { x: 0, y: 0 };
+    for goal in grid.points().filter(|p| grid[*p] != '#') {
+        let by_dijkstra = dijkstra(start, |p| maze_steps(&grid, p), |p| *p == goal).unwrap();
+        let by_astar = astar(
+            start,
+            |p| maze_steps(&grid, p),
+            |p| (p.x.abs_diff(goal.x) + p.y.abs_diff(goal.y)) as u64,
+            |p| *p == goal,
+        ).unwrap();
+        assert_eq!(by_astar.cost, by_dijkstra.cost, "to {:?}", goal);
+    }
+}
+
+#[test]
+fn path_steps_between_neighbours_and_adds_up_to_the_cost() {
+    let grid = maze();
+    let (start, goal) = (Point { x: 0, y: 0 }, Point { x: 7, y: 6 });
+    let path = dijkstra(start, |p| maze_steps(&grid, p), |p| *p == goal).unwrap();
+    assert_eq!(path.nodes.first(), Some(&start));
+    assert_eq!(path.nodes.last(), Some(&goal));
+
+    let mut cost = 0;
+    for step in path.nodes.windows(2) {
+        let (_, c) = maze_steps(&grid, &step[0]).into_iter().find(|(n, _)| *n == step[1]).expect("not a neighbour");
+        cost += c;
+    }
+    assert_eq!(cost, path.cost);
+    // Along the top and down the right side misses every 9, so it costs no more than the steps taken
+    assert_eq!(path.cost, 13);
+}